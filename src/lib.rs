@@ -1,6 +1,23 @@
 #![doc = include_str!("../README.md")]
 #![no_std]
 
+#[cfg(any(feature = "std", feature = "tokio", feature = "futures-io"))]
+#[doc(hidden)]
+pub extern crate std as __std;
+
+#[cfg(feature = "bytemuck")]
+#[doc(hidden)]
+pub use bytemuck as __bytemuck;
+#[cfg(feature = "futures-io")]
+#[doc(hidden)]
+pub use futures_io as __futures_io;
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde as __serde;
+#[cfg(feature = "tokio")]
+#[doc(hidden)]
+pub use tokio as __tokio;
+
 #[macro_export]
 /// Helper macro for creating a wrapper over any type (new-type idiom).
 ///
@@ -137,7 +154,9 @@ macro_rules! general_wrapper {
 /// - When there's no default value specified, we cannot implement the `From`
 ///   trait for the wrapper type.
 /// - The macro does not know if other fields were zero-sized types (ZST), hence
-///   we will not automatically apply `repr(transparent)` attribute.
+///   we will not automatically apply `repr(transparent)` attribute, unless an
+///   attribute requiring it (`Pod`, `RefCast`, etc.) is given. The compiler
+///   then checks that the other fields are ZSTs.
 ///
 /// ## Special usages
 ///
@@ -178,11 +197,11 @@ macro_rules! general_wrapper {
 ///     "ExampleWrapperDebugName"
 /// );
 /// ```
-/// 
+///
 /// ### `Display`
-/// 
+///
 /// Like `Debug`.
-/// 
+///
 /// ```rust
 /// wrapper_lite::wrapper!(
 ///     #[wrapper_impl(Display)]
@@ -199,261 +218,2261 @@ macro_rules! general_wrapper {
 /// );
 /// ```
 ///
-/// ### `ConstAsMut`
+/// ### `PartialEq`, `Eq`, `PartialOrd`, `Ord` and `Hash`
 ///
-/// Like `AsMut`, but instead generates a const version of `as_inner_mut` method
-/// (stable since Rust 1.83.0+).
+/// Unlike `#[derive(...)]`, these attributes compare and hash the inner value
+/// only, and are bounded on the inner type only. This is useful for wrapper
+/// types with multiple fields, where other fields are usually markers.
+///
+/// Like `#[derive(...)]`, the supertraits must be specified as well, e.g.,
+/// `Ord` requires `PartialOrd` and `Eq`.
+///
+/// ```rust
+/// struct NotComparable;
 ///
-/// ```rust,no_run
 /// wrapper_lite::wrapper!(
-///     #[wrapper_impl(ConstAsMut)]
-///     #[derive(Debug, Clone, Copy)]
-///     pub struct ExampleWrapper<P>(pub(crate) P);
+///     #[wrapper_impl(PartialEq)]
+///     #[wrapper_impl(Eq)]
+///     #[wrapper_impl(PartialOrd)]
+///     #[wrapper_impl(Ord)]
+///     #[wrapper_impl(Hash)]
+///     pub struct ExampleWrapperCmp<M> {
+///         inner: u32,
+///         _marker: ::core::marker::PhantomData<M> = ::core::marker::PhantomData,
+///     }
 /// );
 ///
-/// const fn const_fn_example<P>(w: &mut ExampleWrapper<P>) -> &mut P {
-///     w.as_inner_mut()
-/// }
+/// assert!(
+///     ExampleWrapperCmp::<NotComparable>::const_from(1)
+///         < ExampleWrapperCmp::<NotComparable>::const_from(2)
+/// );
 /// ```
 ///
-/// ### `AsRef<T>`, `AsMut<T>`, `Borrow<T>`, `BorrowMut<T>`, `Deref<T>`, `DerefMut<T>`
+/// ### `PartialEq<T>` and `PartialOrd<T>`
 ///
-/// These attributes allow you to specify a target type `T` for the respective
-/// traits (experimental). This is done by auto-dereferencing the inner type to
-/// get a reference to `T`.
+/// These attributes implement comparisons between the wrapper type and a
+/// concrete type `T`, in both directions, i.e., `Wrapper op T` and `T op
+/// Wrapper`. `T` can be the inner type itself, or any type the inner type can
+/// be compared with, like `str` and `&str` for `String`. Like `PartialOrd`,
+/// `PartialOrd<T>` requires `PartialEq<T>` to be specified as well.
+///
+/// Both directions are bounded on the inner type, so generic wrapper types are
+/// supported. However, due to the orphan rules, the `T op Wrapper` direction
+/// can only be implemented when `T` is a local type or a type not being a
+/// generic parameter, so `PartialEq<P>` with `P` being a generic parameter of
+/// the wrapper type will fail to compile.
 ///
 /// ```rust
 /// wrapper_lite::wrapper!(
-///     #[wrapper_impl(AsRef<[u8]>)]
-///     #[wrapper_impl(AsMut<[u8]>)]
-///     // #[wrapper_impl(Borrow<[u8]>)]
-///     #[wrapper_impl(BorrowMut<[u8]>)]
-///     // #[wrapper_impl(Deref<[u8]>)]
-///     #[wrapper_impl(DerefMut<[u8]>)]
-///     pub struct ExampleWrapper(pub(crate) Vec<u8>);
+///     #[wrapper_impl(PartialEq<str>)]
+///     #[wrapper_impl(PartialEq<&str>)]
+///     #[wrapper_impl(PartialEq<String>)]
+///     #[wrapper_impl(PartialOrd<String>)]
+///     pub struct ExampleWrapperName(String);
 /// );
+///
+/// let name = ExampleWrapperName::const_from("abc".to_string());
+///
+/// assert!(name == "abc");
+/// assert!("abc" == name);
+/// assert!(name < "abd".to_string());
 /// ```
 ///
-/// ### `repr(align(cache))`
+/// ```rust
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(PartialEq<&str>)]
+///     pub struct ExampleWrapperGenericName<T>(T);
+/// );
 ///
-/// You can use `#[repr(align(cache))]` to pad and align the wrapper type to the
-/// cache line size. This is useful for performance optimization in certain
-/// scenarios.
+/// let name = ExampleWrapperGenericName::const_from("abc".to_string());
 ///
+/// assert!(name == "abc");
+/// assert!("abc" == name);
 /// ```
+///
+/// ### `Hasher`, `BuildHasher` and `BuildHasher<T>`
+///
+/// All the `write_*` methods of `Hasher` are forwarded. With a target,
+/// `BuildHasher::Hasher` is the given type, created from the hasher built by
+/// the inner value with its `From` impl, e.g., `#[wrapper_impl(From)]`.
+///
+/// ```rust
+/// use std::collections::hash_map::{DefaultHasher, RandomState};
+/// use std::collections::HashMap;
+///
 /// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Hasher)]
 ///     #[wrapper_impl(From)]
-///     #[repr(align(cache))]
-///     /// Example doc
-///     pub struct ExampleWrapperCachePadded(u64);
+///     pub struct ExampleWrapperHasher(DefaultHasher);
 /// );
-/// #[cfg(target_arch = "x86_64")]
-/// assert_eq!(core::mem::align_of::<ExampleWrapperCachePadded>(), 128);
+///
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(BuildHasher<ExampleWrapperHasher>)]
+///     pub struct ExampleWrapperState(RandomState);
+/// );
+///
+/// let mut map = HashMap::with_hasher(ExampleWrapperState::const_from(RandomState::new()));
+///
+/// map.insert("a", 1);
+///
+/// assert_eq!(map["a"], 1);
 /// ```
 ///
-/// Credits: <https://docs.rs/crossbeam/latest/crossbeam/utils/struct.CachePadded.html>.
+/// ### `Clone` and `Copy`
 ///
-/// Notes that `repr(align(cache))` must be placed after other
-/// `#[wrapper_impl(...)]` attributes and before any other attributes, including
-/// docs.
+/// Unlike `#[derive(Clone, Copy)]` which requires all generic parameters to be
+/// `Clone` / `Copy`, these attributes are bounded on the field types instead.
 ///
-/// ## Notes
+/// ```rust
+/// struct NotClone;
 ///
-/// - The `wrapper_impl` attribute must be on top of any other attributes.
-/// - For `BorrowMut` and `DerefMut`, the macro will automatically implement the
-///   corresponding `Borrow` and `Deref` traits, so the following two examples
-///   will fail to compile:
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Clone)]
+///     #[wrapper_impl(Copy)]
+///     pub struct ExampleWrapperRef<'a, P>(&'a P);
+/// );
 ///
-///   ```rust,compile_fail
-///   wrapper_lite::wrapper!(
-///       #[wrapper_impl(Borrow)]
-///       #[wrapper_impl(BorrowMut)]
-///       pub struct ExampleWrapper<P>(pub(crate) P);
-///   );
-///   ```
+/// let data = NotClone;
+/// let wrapper = ExampleWrapperRef::const_from(&data);
+/// let copied = wrapper;
+/// let _ = (wrapper, copied);
+/// ```
 ///
-///   ```rust,compile_fail
-///   wrapper_lite::wrapper!(
-///       #[wrapper_impl(Deref)]
-///       #[wrapper_impl(DerefMut)]
-///       pub struct ExampleWrapper<P>(pub(crate) P);
-///   );
-///   ```
-macro_rules! wrapper {
-    // To filter out the `wrapper_impl` attribute and extract the inner type.
-    (
-        @INTERNAL IMPL
-        #[wrapper_impl(AsRef $(<$target:ty>)? )]
-        $($tt:tt)*
-    ) => {
-        $crate::wrapper! {
-            @INTERNAL IMPL
-            $($tt)*
-        }
-    };
-    (
-        @INTERNAL IMPL
-        #[wrapper_impl(AsMut $(<$target:ty>)? )]
-        $($tt:tt)*
-    ) => {
-        $crate::wrapper! {
-            @INTERNAL IMPL
-            $($tt)*
-        }
-    };
-    (
-        @INTERNAL IMPL
-        #[wrapper_impl(ConstAsMut $(<$target:ty>)? )]
-        $($tt:tt)*
-    ) => {
-        $crate::wrapper! {
-            @INTERNAL IMPL
-            $($tt)*
-        }
-    };
-    (
-        @INTERNAL IMPL
-        #[wrapper_impl(Borrow $(<$target:ty>)? )]
-        $($tt:tt)*
-    ) => {
-        $crate::wrapper! {
-            @INTERNAL IMPL
-            $($tt)*
-        }
-    };
-    (
-        @INTERNAL IMPL
-        #[wrapper_impl(BorrowMut $(<$target:ty>)? )]
-        $($tt:tt)*
-    ) => {
-        $crate::wrapper! {
-            @INTERNAL IMPL
-            $($tt)*
-        }
-    };
-    (
-        @INTERNAL IMPL
-        #[wrapper_impl(Deref $(<$target:ty>)? )]
-        $($tt:tt)*
-    ) => {
-        $crate::wrapper! {
-            @INTERNAL IMPL
-            $($tt)*
-        }
-    };
-    (
-        @INTERNAL IMPL
-        #[wrapper_impl(DerefMut $(<$target:ty>)? )]
-        $($tt:tt)*
-    ) => {
-        $crate::wrapper! {
-            @INTERNAL IMPL
-            $($tt)*
-        }
-    };
-    (
-        @INTERNAL IMPL
-        #[wrapper_impl(From)]
-        $($tt:tt)*
-    ) => {
-        $crate::wrapper! {
-            @INTERNAL IMPL
-            $($tt)*
-        }
-    };
-    (
-        @INTERNAL IMPL
-        #[wrapper_impl(Debug)]
-        $($tt:tt)*
-    ) => {
-        $crate::wrapper! {
-            @INTERNAL IMPL
-            $($tt)*
-        }
-    };
-    (
-        @INTERNAL IMPL
-        #[wrapper_impl(DebugName)]
-        $($tt:tt)*
-    ) => {
-        $crate::wrapper! {
-            @INTERNAL IMPL
-            $($tt)*
-        }
-    };
-    (
-        @INTERNAL IMPL
-        #[wrapper_impl(Display)]
-        $($tt:tt)*
-    ) => {
-        $crate::wrapper! {
-            @INTERNAL IMPL
-            $($tt)*
-        }
-    };
-
-    // The actual implementation of the wrapper type: `pub Name<...>(...)`
-    (
-        @INTERNAL IMPL
-        #[repr(align(cache))]
-        $(#[$outer:meta])*
-        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
-    ) => {
-        // Starting from Intel's Sandy Bridge, spatial prefetcher is now pulling pairs of 64-byte cache
-        // lines at a time, so we have to align to 128 bytes rather than 64.
-        //
-        // Sources:
-        // - https://www.intel.com/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-optimization-manual.pdf
-        // - https://github.com/facebook/folly/blob/1b5288e6eea6df074758f877c849b6e73bbb9fbb/folly/lang/Align.h#L107
-        //
-        // aarch64/arm64ec's big.LITTLE architecture has asymmetric cores and "big" cores have 128-byte cache line size.
-        //
-        // Sources:
-        // - https://www.mono-project.com/news/2016/09/12/arm64-icache/
-        //
-        // powerpc64 has 128-byte cache line size.
-        //
-        // Sources:
-        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_ppc64x.go#L9
-        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/powerpc/include/asm/cache.h#L26
-        #[cfg_attr(
-            any(
-                target_arch = "x86_64",
-                target_arch = "aarch64",
-                target_arch = "arm64ec",
-                target_arch = "powerpc64",
-            ),
-            repr(align(128))
-        )]
-        // arm, mips, mips64, sparc, and hexagon have 32-byte cache line size.
-        //
-        // Sources:
-        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_arm.go#L7
-        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_mips.go#L7
-        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_mipsle.go#L7
-        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_mips64x.go#L9
-        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/sparc/include/asm/cache.h#L17
-        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/hexagon/include/asm/cache.h#L12
-        #[cfg_attr(
-            any(
-                target_arch = "arm",
-                target_arch = "mips",
-                target_arch = "mips32r6",
-                target_arch = "mips64",
-                target_arch = "mips64r6",
-                target_arch = "sparc",
-                target_arch = "hexagon",
-            ),
-            repr(align(32))
-        )]
-        // m68k has 16-byte cache line size.
-        //
-        // Sources:
-        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/m68k/include/asm/cache.h#L9
-        #[cfg_attr(target_arch = "m68k", repr(align(16)))]
-        // s390x has 256-byte cache line size.
-        //
-        // Sources:
+/// ### `FromStr`
+///
+/// Implements the `FromStr` trait by parsing the inner value, with the same
+/// error type as the inner one. Like `From`, for wrapper types with multiple
+/// fields, the other fields are filled with their default values.
+///
+/// ```rust
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(FromStr)]
+///     #[derive(Debug, PartialEq)]
+///     pub struct ExampleWrapperPort(u16);
+/// );
+///
+/// assert_eq!("8080".parse(), Ok(ExampleWrapperPort::const_from(8080)));
+/// assert!("65536".parse::<ExampleWrapperPort>().is_err());
+/// ```
+///
+/// ### `Default`
+///
+/// Unlike `#[derive(Default)]`, this attribute creates the inner value with
+/// `Default::default()` and fills other fields with their declared default
+/// values, bounded on the inner type only. The wrapper type is created with
+/// `const_from`, so this cannot be used together with `TryFrom`, as the default
+/// inner value is not validated.
+///
+/// ```rust
+/// struct NotDefault;
+///
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Default)]
+///     pub struct ExampleWrapperDefault<M> {
+///         inner: String,
+///         _marker: ::core::marker::PhantomData<M> = ::core::marker::PhantomData,
+///         capacity: usize = 1024,
+///     }
+/// );
+///
+/// let wrapper = ExampleWrapperDefault::<NotDefault>::default();
+/// assert_eq!(wrapper.capacity, 1024);
+/// ```
+///
+/// ```rust,compile_fail
+/// # fn is_lowercase(inner: &str) -> bool {
+/// #     inner.chars().all(|c| c.is_ascii_lowercase())
+/// # }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Default)]
+///     #[wrapper_impl(TryFrom(validate = is_lowercase))]
+///     pub struct ExampleWrapperSlug(String);
+/// );
+/// ```
+///
+/// ### `Into` and `IntoInner`
+///
+/// `#[wrapper_impl(IntoInner)]` generates a method `into_inner` that consumes
+/// the wrapper and returns the inner value, dropping other fields if any.
+///
+/// `#[wrapper_impl(Into)]` additionally implements `From<Wrapper>` for the
+/// inner type. Due to the orphan rules, this only works when the inner type is
+/// not a generic parameter, and specifying both attributes will fail to
+/// compile.
+///
+/// ```rust
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Into)]
+///     pub struct ExampleWrapperInto(String);
+/// );
+///
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(IntoInner)]
+///     pub struct ExampleWrapperIntoInner<P> {
+///         inner: P,
+///         _marker: ::core::marker::PhantomData<()>,
+///     }
+/// );
+///
+/// let inner: String = ExampleWrapperInto::const_from("Hello".to_string()).into();
+/// assert_eq!(inner, "Hello");
+///
+/// let wrapper = ExampleWrapperIntoInner {
+///     inner: 42,
+///     _marker: ::core::marker::PhantomData,
+/// };
+/// assert_eq!(wrapper.into_inner(), 42);
+/// ```
+///
+/// ### `TryFrom(validate = ..)`
+///
+/// For wrapper types carrying invariants, `#[wrapper_impl(TryFrom(validate =
+/// path::to::check))]` implements the `TryFrom` trait with the given validation
+/// function, and generates a fallible constructor `try_new` as well as an
+/// unsafe one `new_unchecked` skipping the validation.
+///
+/// - `TryFrom(validate = check)`: `check` is a `fn(&Inner) -> bool`, and the
+///   rejected inner value is returned as the error.
+/// - `TryFrom(validate = check, error = Error)`: `check` is a `fn(&Inner) ->
+///   Result<(), E>` where `Error: From<E>`.
+///
+/// ```rust
+/// fn is_lowercase(inner: &str) -> bool {
+///     inner.chars().all(|c| c.is_ascii_lowercase())
+/// }
+///
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(TryFrom(validate = is_lowercase))]
+///     pub struct ExampleWrapperSlug(String);
+/// );
+///
+/// assert!(ExampleWrapperSlug::try_new("abc".to_string()).is_ok());
+/// assert!(ExampleWrapperSlug::try_new("aBc".to_string()).is_err());
+/// ```
+///
+/// To keep the invariant from being bypassed, `const_from` is not generated for
+/// such wrapper types, and the following are rejected at compile time:
+///
+/// - a public inner field;
+/// - attributes creating the wrapper type from arbitrary inner values
+///   (`Default`, `From`, `FromStr`, `FromIterator`, `Sum`, `Product`,
+///   `Deserialize`, `Zeroable`, `Pod`, `TransparentWrapper`, `Zerocopy`,
+///   `RefCast` and the operators);
+/// - attributes exposing the inner value mutably (`AsMut`, `ConstAsMut`,
+///   `BorrowMut`, `DerefMut`, `IndexMut`, `Extend`, `IntoIterator`, the
+///   iterator and I/O traits, `Hasher`, `FmtWrite`, `Future` and
+///   `PinProject`).
+///
+/// Besides, the inner type cannot be a generic parameter, which conflicts with
+/// the blanket `TryFrom` impl.
+///
+/// ```rust,compile_fail
+/// # fn is_lowercase(inner: &str) -> bool {
+/// #     inner.chars().all(|c| c.is_ascii_lowercase())
+/// # }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(TryFrom(validate = is_lowercase))]
+///     pub struct ExampleWrapperSlug(String);
+/// );
+///
+/// let _ = ExampleWrapperSlug::const_from("aBc".to_string());
+/// ```
+///
+/// ```rust,compile_fail
+/// # fn is_lowercase(inner: &str) -> bool {
+/// #     inner.chars().all(|c| c.is_ascii_lowercase())
+/// # }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(From)]
+///     #[wrapper_impl(TryFrom(validate = is_lowercase))]
+///     pub struct ExampleWrapperSlug(String);
+/// );
+/// ```
+///
+/// ```rust,compile_fail
+/// # fn is_lowercase(inner: &str) -> bool {
+/// #     inner.chars().all(|c| c.is_ascii_lowercase())
+/// # }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(DerefMut)]
+///     #[wrapper_impl(TryFrom(validate = is_lowercase))]
+///     pub struct ExampleWrapperSlug(String);
+/// );
+/// ```
+///
+/// ```rust,compile_fail
+/// # fn is_lowercase(inner: &str) -> bool {
+/// #     inner.chars().all(|c| c.is_ascii_lowercase())
+/// # }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(TryFrom(validate = is_lowercase))]
+///     pub struct ExampleWrapperSlug(pub String);
+/// );
+/// ```
+///
+/// ### `LowerHex`, `UpperHex`, `Binary`, `Octal`, `LowerExp`, `UpperExp` and `Pointer`
+///
+/// Like `Display`, the formatter flags (width, padding, `#`, etc.) are passed to
+/// the inner value as is. `#[wrapper_impl(NumericFmt)]` is a shorthand for all
+/// the above except `Pointer`.
+///
+/// ```rust
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(NumericFmt)]
+///     pub struct ExampleWrapperId(u32);
+/// );
+///
+/// let id = ExampleWrapperId::const_from(255);
+///
+/// assert_eq!(format!("{:#06x}", id), "0x00ff");
+/// assert_eq!(format!("{:X}", id), "FF");
+/// assert_eq!(format!("{:b}", id), "11111111");
+/// assert_eq!(format!("{:o}", id), "377");
+/// assert_eq!(format!("{:e}", id), "2.55e2");
+/// ```
+///
+/// ### `IntoIterator`, `FromIterator` and `Extend`
+///
+/// `IntoIterator` is implemented for `W`, `&W` and `&mut W`, each delegating to
+/// the corresponding impl of the inner type. `FromIterator` builds the wrapper
+/// with `const_from`, so the struct form requires default values for all the
+/// other fields.
+///
+/// ```rust
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(IntoIterator)]
+///     #[wrapper_impl(FromIterator)]
+///     #[wrapper_impl(Extend)]
+///     pub struct ExampleWrapperList(Vec<u8>);
+/// );
+///
+/// let mut list: ExampleWrapperList = (1..=3).collect();
+///
+/// list.extend([4, 5]);
+///
+/// for x in &mut list {
+///     *x *= 2;
+/// }
+///
+/// assert_eq!((&list).into_iter().sum::<u8>(), 30);
+/// assert_eq!(list.into_iter().collect::<Vec<_>>(), [2, 4, 6, 8, 10]);
+/// ```
+///
+/// ### `Index`, `IndexMut`, `Index<T>` and `IndexMut<T>`
+///
+/// Without a target, the impls are generic over any index type the inner type
+/// supports. With a target, only that index type is forwarded; the two forms
+/// cannot be combined for the same wrapper type.
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(IndexMut)]
+///     pub struct ExampleWrapperList(Vec<u8>);
+/// );
+///
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Index<&str>)]
+///     pub struct ExampleWrapperMap<K>(HashMap<K, u8>);
+/// );
+///
+/// let mut list = ExampleWrapperList::const_from(vec![1, 2, 3]);
+///
+/// list[0] = 42;
+///
+/// assert_eq!(list[0], 42);
+/// assert_eq!(&list[1..], [2, 3]);
+///
+/// let map = ExampleWrapperMap::const_from(HashMap::from([("a".to_string(), 1)]));
+///
+/// assert_eq!(map["a"], 1);
+/// ```
+///
+/// ### `Iterator`, `DoubleEndedIterator`, `ExactSizeIterator` and `FusedIterator`
+///
+/// Besides `next`, the stable methods the inner iterator may specialize
+/// (`size_hint`, `nth`, `fold`, `collect`, `sum`, `cmp`, etc.) are forwarded as
+/// well. The adapters (`map`, `filter`, etc.), the methods built on the
+/// unstable `Try` trait (`try_fold`, `try_for_each`), `unzip` and `rposition`
+/// fall back to their default implementations.
+///
+/// `Iterator` cannot be used together with `IntoIterator`, which conflicts with
+/// the blanket `IntoIterator` impl for all iterators.
+///
+/// ```rust
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Iterator)]
+///     #[wrapper_impl(DoubleEndedIterator)]
+///     #[wrapper_impl(ExactSizeIterator)]
+///     #[wrapper_impl(FusedIterator)]
+///     pub struct ExampleWrapperIter<'a>(core::slice::Iter<'a, u8>);
+/// );
+///
+/// let mut iter = ExampleWrapperIter::const_from([1, 2, 3, 4].iter());
+///
+/// assert_eq!(iter.len(), 4);
+/// assert_eq!(iter.next(), Some(&1));
+/// assert_eq!(iter.next_back(), Some(&4));
+/// assert_eq!(iter.size_hint(), (2, Some(2)));
+/// assert_eq!(iter.sum::<u8>(), 5);
+/// ```
+///
+/// ```rust,compile_fail
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Iterator)]
+///     #[wrapper_impl(IntoIterator)]
+///     pub struct ExampleWrapperIter(std::vec::IntoIter<u8>);
+/// );
+/// ```
+///
+/// ### `Sum` and `Product`
+///
+/// Both are implemented for iterators over `W` and `&W`, building the result
+/// with `const_from`.
+///
+/// ```rust
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Sum)]
+///     #[wrapper_impl(Product)]
+///     #[derive(Debug, PartialEq)]
+///     pub struct ExampleWrapperBytes(u64);
+/// );
+///
+/// let list = vec![
+///     ExampleWrapperBytes::const_from(2),
+///     ExampleWrapperBytes::const_from(3),
+/// ];
+///
+/// assert_eq!(
+///     list.iter().sum::<ExampleWrapperBytes>(),
+///     ExampleWrapperBytes::const_from(5)
+/// );
+/// assert_eq!(
+///     list.into_iter().product::<ExampleWrapperBytes>(),
+///     ExampleWrapperBytes::const_from(6)
+/// );
+/// ```
+///
+/// ### `Error`
+///
+/// Requires the `std` feature (`core::error::Error` is not available under the
+/// MSRV). Only `source` is forwarded to the inner error, and the wrapper type
+/// must implement `Debug` and `Display` itself, e.g. with the `Debug` and
+/// `Display` wrapper impls.
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use std::error::Error;
+/// use std::io;
+///
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Debug)]
+///     #[wrapper_impl(Display)]
+///     #[wrapper_impl(Error)]
+///     pub struct ExampleWrapperError(io::Error);
+/// );
+///
+/// let inner = io::Error::new(io::ErrorKind::Other, "oops");
+/// let error = ExampleWrapperError::const_from(inner);
+///
+/// assert_eq!(error.to_string(), "oops");
+/// assert!(error.source().is_none());
+/// # }
+/// ```
+///
+/// ### `Read`, `Write`, `Seek`, `BufRead` and `FmtWrite`
+///
+/// `Read`, `Write`, `Seek` and `BufRead` are the `std::io` traits and require
+/// the `std` feature, while `FmtWrite` (`core::fmt::Write`) does not. Methods
+/// with specialized impls in the inner type (vectored I/O, `read_exact`,
+/// `write_all`, etc.) are forwarded as well.
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use std::io::{BufRead, Read, Write};
+///
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Read)]
+///     #[wrapper_impl(BufRead)]
+///     pub struct ExampleWrapperReader<'a>(&'a [u8]);
+/// );
+///
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(AsRef)]
+///     #[wrapper_impl(Write)]
+///     pub struct ExampleWrapperSink(Vec<u8>);
+/// );
+///
+/// let mut reader = ExampleWrapperReader::const_from(&b"hello\nworld"[..]);
+/// let mut line = String::new();
+///
+/// reader.read_line(&mut line).unwrap();
+/// assert_eq!(line, "hello\n");
+///
+/// let mut sink = ExampleWrapperSink::const_from(Vec::new());
+///
+/// std::io::copy(&mut reader, &mut sink).unwrap();
+/// assert_eq!(sink.as_inner(), b"world");
+/// # }
+/// ```
+///
+/// ```rust
+/// use core::fmt::Write;
+///
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(AsRef)]
+///     #[wrapper_impl(FmtWrite)]
+///     pub struct ExampleWrapperBuf(String);
+/// );
+///
+/// let mut buf = ExampleWrapperBuf::const_from(String::new());
+///
+/// write!(buf, "{}-{}", 1, 2).unwrap();
+/// assert_eq!(buf.as_inner(), "1-2");
+/// ```
+///
+/// ### `PinProject` and `Future`
+///
+/// `PinProject` generates `as_inner_pin` and `as_inner_pin_mut`, projecting
+/// `Pin<&Self>` and `Pin<&mut Self>` to the inner field. The inner field is
+/// structurally pinned: the wrapper type is `Unpin` only if the inner type is,
+/// and the wrapper type must not implement `Unpin` or `Drop` by itself
+/// (rejected at compile time), so that the projection is sound.
+///
+/// `Future` and the async I/O traits below also generate these methods and
+/// poll the inner value through `as_inner_pin_mut`, and they can be combined
+/// with each other and with `PinProject` freely.
+///
+/// ```rust
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(PinProject)]
+///     pub struct ExampleWrapperPinned<S> {
+///         inner: S,
+///         _pinned: core::marker::PhantomPinned = core::marker::PhantomPinned,
+///     }
+/// );
+///
+/// let mut wrapper = Box::pin(ExampleWrapperPinned::const_from(42u8));
+///
+/// assert_eq!(*wrapper.as_mut().as_inner_pin_mut(), 42);
+/// assert_eq!(*wrapper.as_ref().as_inner_pin(), 42);
+/// ```
+///
+/// ```rust,compile_fail
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(PinProject)]
+///     pub struct ExampleWrapperPinned<S> {
+///         inner: S,
+///         _pinned: core::marker::PhantomPinned = core::marker::PhantomPinned,
+///     }
+/// );
+///
+/// impl<S> Drop for ExampleWrapperPinned<S> {
+///     fn drop(&mut self) {}
+/// }
+/// ```
+///
+/// ```rust
+/// use core::future::Future;
+///
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Future)]
+///     pub struct ExampleWrapperTimeout<F>(F);
+/// );
+///
+/// fn assert_future<F: Future<Output = u8>>(_: &F) {}
+///
+/// assert_future(&ExampleWrapperTimeout::const_from(async { 42 }));
+/// ```
+///
+/// ```rust,compile_fail
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Future)]
+///     pub struct ExampleWrapperTimeout<F>(F);
+/// );
+///
+/// impl<F> Drop for ExampleWrapperTimeout<F> {
+///     fn drop(&mut self) {}
+/// }
+/// ```
+///
+/// ```rust,compile_fail
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Future)]
+///     pub struct ExampleWrapperTimeout<F>(F);
+/// );
+///
+/// impl<F> Unpin for ExampleWrapperTimeout<F> {}
+/// ```
+///
+/// ```rust,compile_fail
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Future)]
+///     pub struct ExampleWrapperTimeout<F>(F);
+/// );
+///
+/// fn assert_unpin<T: Unpin>(_: &T) {}
+///
+/// assert_unpin(&ExampleWrapperTimeout::const_from(async { 42 }));
+/// ```
+///
+/// ### `TokioAsyncRead`, `TokioAsyncWrite`, `TokioAsyncBufRead` and `TokioAsyncSeek`
+///
+/// Implement `tokio::io::{AsyncRead, AsyncWrite, AsyncBufRead, AsyncSeek}`,
+/// requiring the `tokio` feature.
+///
+/// ### `FuturesAsyncRead`, `FuturesAsyncWrite`, `FuturesAsyncBufRead` and `FuturesAsyncSeek`
+///
+/// Implement `futures_io::{AsyncRead, AsyncWrite, AsyncBufRead, AsyncSeek}`,
+/// requiring the `futures-io` feature.
+///
+/// All of them project `Pin<&mut Self>` to the inner field like `PinProject`.
+///
+/// ```rust
+/// # #[cfg(feature = "tokio")] {
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(TokioAsyncRead)]
+///     #[wrapper_impl(TokioAsyncWrite)]
+///     pub struct ExampleWrapperStream(tokio::io::DuplexStream);
+/// );
+///
+/// fn assert_stream<S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin>() {}
+///
+/// assert_stream::<ExampleWrapperStream>();
+/// # }
+/// ```
+///
+/// ```rust
+/// # #[cfg(feature = "futures-io")] {
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(FuturesAsyncRead)]
+///     pub struct ExampleWrapperReader<R>(R);
+/// );
+///
+/// fn assert_reader<R: futures_io::AsyncRead>() {}
+///
+/// assert_reader::<ExampleWrapperReader<&[u8]>>();
+/// # }
+/// ```
+///
+/// ### `Serialize` and `Deserialize`
+///
+/// Require the `serde` feature. The wrapper type is serialized exactly like
+/// the inner value, and deserialized with `const_from`, so the struct form
+/// requires default values for all the other fields.
+///
+/// ```rust
+/// # #[cfg(feature = "serde")] {
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Serialize)]
+///     #[wrapper_impl(Deserialize)]
+///     #[derive(Debug, PartialEq)]
+///     pub struct ExampleWrapperId<'a> {
+///         inner: u64,
+///         _a: core::marker::PhantomData<&'a ()> = core::marker::PhantomData,
+///     }
+/// );
+///
+/// let id = ExampleWrapperId::const_from(42);
+///
+/// assert_eq!(serde_json::to_string(&id).unwrap(), "42");
+/// assert_eq!(serde_json::from_str::<ExampleWrapperId>("42").unwrap(), id);
+/// # }
+/// ```
+///
+/// ### `SerdeAsString`
+///
+/// Requires the `serde` feature. Implements `Serialize` with the `Display` impl
+/// of the wrapper type and `Deserialize` with its `FromStr` impl, which can be
+/// generated with the `Display` and `FromStr` wrapper impls. Parse errors are
+/// reported through `serde::de::Error::custom`.
+///
+/// ```rust
+/// # #[cfg(feature = "serde")] {
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Display)]
+///     #[wrapper_impl(FromStr)]
+///     #[wrapper_impl(SerdeAsString)]
+///     #[derive(Debug, PartialEq)]
+///     pub struct ExampleWrapperId(u64);
+/// );
+///
+/// let id = ExampleWrapperId::const_from(42);
+///
+/// assert_eq!(serde_json::to_string(&id).unwrap(), r#""42""#);
+/// assert_eq!(serde_json::from_str::<ExampleWrapperId>(r#""42""#).unwrap(), id);
+/// assert!(serde_json::from_str::<ExampleWrapperId>(r#""x""#).is_err());
+/// # }
+/// ```
+///
+/// ### `Zeroable`, `Pod` and `TransparentWrapper`
+///
+/// Require the `bytemuck` feature, and `Pod` implies `Zeroable`. The unsafe
+/// impls rely on the wrapper type being `repr(transparent)`, which is always
+/// the case for the tuple form, and added for the named form, so that other
+/// fields must be ZSTs like `PhantomData`. They are bounded on the same trait
+/// (`Zeroable` for `TransparentWrapper`). Wrapper types with
+/// `repr(align(cache))` are rejected at compile time, as well as the ones with
+/// `TryFrom`, whose validation would be bypassed. `Pod` also requires the
+/// wrapper type to be `Copy`.
+///
+/// ```rust
+/// # #[cfg(feature = "bytemuck")] {
+/// use bytemuck::TransparentWrapper;
+///
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(AsRef)]
+///     #[wrapper_impl(Pod)]
+///     #[wrapper_impl(TransparentWrapper)]
+///     #[derive(Debug, Clone, Copy, PartialEq)]
+///     pub struct ExampleWrapperPort(u16);
+/// );
+///
+/// let ports: &[ExampleWrapperPort] = bytemuck::cast_slice(&[80u16, 443]);
+///
+/// assert_eq!(ports[1], ExampleWrapperPort::const_from(443));
+/// assert_eq!(ExampleWrapperPort::wrap_ref(&8080).as_inner(), &8080);
+/// # }
+/// ```
+///
+/// ### `Zerocopy` and `Unaligned`
+///
+/// Require the `zerocopy` feature. `Zerocopy` derives `FromBytes`,
+/// `IntoBytes`, `KnownLayout` and `Immutable`, while `Unaligned` derives
+/// `Unaligned`, which only holds for inner types with an alignment of 1, e.g.,
+/// `[u8; N]` or `U32<BigEndian>`. Like the `bytemuck` traits, they rely on the
+/// wrapper type being `repr(transparent)`, and cannot be used together with
+/// `repr(align(cache))` or `TryFrom`.
+///
+/// Since the `zerocopy` derives only accept the path to the `zerocopy` crate
+/// as a string literal, where `$crate` is not available, the derives are
+/// referred to as `::zerocopy`, and the crate using them must depend on
+/// `zerocopy` 0.8 directly (the `derive` feature is enabled by `wrapper-lite`).
+/// This works regardless of how `wrapper-lite` itself is named or re-exported.
+///
+/// ```rust
+/// # #[cfg(feature = "zerocopy")] {
+/// use zerocopy::byteorder::{BigEndian, U16};
+/// use zerocopy::{FromBytes, IntoBytes};
+///
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Zerocopy)]
+///     #[wrapper_impl(Unaligned)]
+///     #[derive(Debug, PartialEq)]
+///     pub struct ExampleWrapperPort(U16<BigEndian>);
+/// );
+///
+/// let (port, rest) = ExampleWrapperPort::ref_from_prefix(&[0x01, 0xbb, 0xff]).unwrap();
+///
+/// assert_eq!(port, &ExampleWrapperPort::const_from(U16::new(443)));
+/// assert_eq!(port.as_bytes(), [0x01, 0xbb]);
+/// assert_eq!(rest, [0xff]);
+/// # }
+/// ```
+///
+/// ### `RefCast`
+///
+/// Generates `from_ref` and `from_mut` methods, converting `&Inner` and
+/// `&mut Inner` into `&Wrapper` and `&mut Wrapper` without `unsafe` code at the
+/// call site. `from_ref` is a const fn (stable since Rust 1.58.0+), while
+/// `from_mut` is not, as mutable references in const fns are not available in
+/// the MSRV. Like the `bytemuck` traits, this relies on the wrapper type being
+/// `repr(transparent)`, and cannot be used together with `repr(align(cache))`
+/// or `TryFrom`. For the named form, the other fields must be ZSTs with default
+/// values given. The methods share the visibility of the inner field since they
+/// bypass the constructors.
+///
+/// ```rust
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(RefCast)]
+///     #[wrapper_impl(DerefMut)]
+///     #[derive(Debug, PartialEq)]
+///     pub struct ExampleWrapperCounter(pub u64);
+/// );
+///
+/// const ZERO: &ExampleWrapperCounter = ExampleWrapperCounter::from_ref(&0);
+///
+/// let mut hits = 41;
+/// **ExampleWrapperCounter::from_mut(&mut hits) += 1;
+///
+/// assert_eq!(hits, 42);
+/// assert_eq!(ExampleWrapperCounter::from_ref(&hits), &ExampleWrapperCounter::const_from(42));
+/// assert_eq!(**ZERO, 0);
+/// ```
+///
+/// ```rust,compile_fail
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(RefCast)]
+///     pub struct ExampleWrapperCounter<M> {
+///         inner: u64,
+///         _marker: ::core::marker::PhantomData<M>,
+///     }
+/// );
+/// ```
+///
+/// ### `ConstAsMut`
+///
+/// Like `AsMut`, but instead generates a const version of `as_inner_mut` method
+/// (stable since Rust 1.83.0+).
+///
+/// ```rust,no_run
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(ConstAsMut)]
+///     #[derive(Debug, Clone, Copy)]
+///     pub struct ExampleWrapper<P>(pub(crate) P);
+/// );
+///
+/// const fn const_fn_example<P>(w: &mut ExampleWrapper<P>) -> &mut P {
+///     w.as_inner_mut()
+/// }
+/// ```
+///
+/// ### `AsRef<T>`, `AsMut<T>`, `Borrow<T>`, `BorrowMut<T>`, `Deref<T>`, `DerefMut<T>`
+///
+/// These attributes allow you to specify a target type `T` for the respective
+/// traits (experimental). This is done by auto-dereferencing the inner type to
+/// get a reference to `T`.
+///
+/// ```rust
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(AsRef<[u8]>)]
+///     #[wrapper_impl(AsMut<[u8]>)]
+///     // #[wrapper_impl(Borrow<[u8]>)]
+///     #[wrapper_impl(BorrowMut<[u8]>)]
+///     // #[wrapper_impl(Deref<[u8]>)]
+///     #[wrapper_impl(DerefMut<[u8]>)]
+///     pub struct ExampleWrapper(pub(crate) Vec<u8>);
+/// );
+/// ```
+///
+/// ### `Add`, `Sub`, `Mul`, `Div`, `Rem`, `Neg` and `*Assign`
+///
+/// These attributes forward the arithmetic operators to the inner value. For
+/// binary operators, `Wrapper op Wrapper`, `Wrapper op Inner` and the reference
+/// operand combinations are implemented, each bounded on the inner type
+/// supporting the same operation.
+///
+/// ```rust
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Add)]
+///     #[wrapper_impl(AddAssign)]
+///     #[wrapper_impl(Neg)]
+///     #[derive(Debug, Clone, Copy, PartialEq)]
+///     pub struct ExampleWrapperBytes(i64);
+/// );
+///
+/// let mut bytes = ExampleWrapperBytes::const_from(1) + 2;
+/// bytes += &ExampleWrapperBytes::const_from(3);
+///
+/// assert_eq!(-bytes, ExampleWrapperBytes::const_from(-6));
+/// ```
+///
+/// For wrapper types with multiple fields, the other fields of the resulting
+/// value are filled with their default values, so the binary and unary
+/// operators require default values to be given. The `*Assign` variants only
+/// touch the inner field and do not have such restriction.
+///
+/// ### `BitAnd`, `BitOr`, `BitXor`, `Not`, `Shl`, `Shr` and `*Assign`
+///
+/// Like the arithmetic ones. `Shl` and `Shr` are generic over the right-hand
+/// side, accepting whatever the inner type accepts as the shift amount.
+///
+/// ```rust
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(BitOr)]
+///     #[wrapper_impl(BitAndAssign)]
+///     #[wrapper_impl(Not)]
+///     #[wrapper_impl(Shl)]
+///     #[derive(Debug, Clone, Copy, PartialEq)]
+///     pub struct ExampleWrapperMask(u8);
+/// );
+///
+/// let mut mask = ExampleWrapperMask::const_from(0b0001) << 2u32 | 0b0001;
+/// mask &= !ExampleWrapperMask::const_from(0b0001);
+///
+/// assert_eq!(mask, ExampleWrapperMask::const_from(0b0100));
+/// ```
+///
+/// ### `repr(align(cache))`
+///
+/// You can use `#[repr(align(cache))]` to pad and align the wrapper type to the
+/// cache line size. This is useful for performance optimization in certain
+/// scenarios.
+///
+/// ```
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(From)]
+///     #[repr(align(cache))]
+///     /// Example doc
+///     pub struct ExampleWrapperCachePadded(u64);
+/// );
+/// #[cfg(target_arch = "x86_64")]
+/// assert_eq!(core::mem::align_of::<ExampleWrapperCachePadded>(), 128);
+/// ```
+///
+/// Credits: <https://docs.rs/crossbeam/latest/crossbeam/utils/struct.CachePadded.html>.
+///
+/// Notes that `repr(align(cache))` must be placed after other
+/// `#[wrapper_impl(...)]` attributes and before any other attributes, including
+/// docs.
+///
+/// ## Notes
+///
+/// - The `wrapper_impl` attribute must be on top of any other attributes.
+/// - For `BorrowMut`, `DerefMut`, `IndexMut` and `Pod`, the macro will
+///   automatically implement the corresponding `Borrow`, `Deref`, `Index` and
+///   `Zeroable` traits, so the following two examples will fail to compile:
+///
+///   ```rust,compile_fail
+///   wrapper_lite::wrapper!(
+///       #[wrapper_impl(Borrow)]
+///       #[wrapper_impl(BorrowMut)]
+///       pub struct ExampleWrapper<P>(pub(crate) P);
+///   );
+///   ```
+///
+///   ```rust,compile_fail
+///   wrapper_lite::wrapper!(
+///       #[wrapper_impl(Deref)]
+///       #[wrapper_impl(DerefMut)]
+///       pub struct ExampleWrapper<P>(pub(crate) P);
+///   );
+///   ```
+macro_rules! wrapper {
+    // To filter out the `wrapper_impl` attribute and extract the inner type.
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(AsRef $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(AsMut $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(ConstAsMut $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Borrow $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(BorrowMut $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Deref $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(DerefMut $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(From)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(FromStr)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Into)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Default)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(IntoIterator)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(FromIterator)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Extend)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Index $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(IndexMut $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Sum)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Iterator)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(DoubleEndedIterator)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(ExactSizeIterator)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(FusedIterator)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Product)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Error)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Hasher)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(BuildHasher $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Read)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Write)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Seek)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(BufRead)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(FmtWrite)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Future)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(PinProject)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(TokioAsyncRead)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(TokioAsyncWrite)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(TokioAsyncBufRead)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(TokioAsyncSeek)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(FuturesAsyncRead)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(FuturesAsyncWrite)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(FuturesAsyncBufRead)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(FuturesAsyncSeek)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Serialize)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Deserialize)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(SerdeAsString)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Zeroable)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL_TRANSPARENT [] []
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Pod)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL_TRANSPARENT [] []
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(TransparentWrapper)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL_TRANSPARENT [] []
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Zerocopy)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL_TRANSPARENT [] [FromBytes, IntoBytes, KnownLayout, Immutable]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Unaligned)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL_TRANSPARENT [] [Unaligned]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(RefCast)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL_TRANSPARENT [] []
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(IntoInner)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(TryFrom($($args:tt)*))]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Debug)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(DebugName)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Display)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(LowerHex)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(UpperHex)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Binary)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Octal)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(LowerExp)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(UpperExp)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Pointer)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(NumericFmt)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(PartialEq $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Eq)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(PartialOrd $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Ord)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Hash)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Clone)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Copy)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Add)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(AddAssign)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Sub)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(SubAssign)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Mul)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(MulAssign)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Div)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(DivAssign)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Rem)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(RemAssign)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Neg)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(BitAnd)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(BitAndAssign)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(BitOr)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(BitOrAssign)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(BitXor)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(BitXorAssign)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Not)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Shl)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(ShlAssign)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(Shr)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL
+        #[wrapper_impl(ShrAssign)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($tt)*
+        }
+    };
+
+    // Make the wrapper type `repr(transparent)` and add the `zerocopy` derives
+    // to it, after the remaining `wrapper_impl` attributes. The tuple form is
+    // always `repr(transparent)`, while the named form gets it unless already
+    // given, so that the compiler checks the other fields are ZSTs. Wrapper
+    // types with `repr(align(cache))` are rejected in `WRAPPER_IMPL`.
+    (
+        @INTERNAL IMPL_TRANSPARENT [$($wrapper_impl:tt)*] [$($derive:ident),*]
+        #[wrapper_impl($($args:tt)*)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL_TRANSPARENT [$($wrapper_impl)* #[wrapper_impl($($args)*)]] [$($derive),*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL_TRANSPARENT [$($wrapper_impl:tt)*] [$($derive:ident),*]
+        #[repr(align(cache))]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($wrapper_impl)*
+            #[repr(align(cache))]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL_TRANSPARENT [$($wrapper_impl:tt)*] [$($derive:ident),*]
+        $(#[$outer:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL_ZEROCOPY_DERIVE [$($wrapper_impl)*] [$($derive),*]
+            $(#[$outer])*
+            $vis struct $name$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ($inner_vis $inner_ty);
+        }
+    };
+    (
+        @INTERNAL IMPL_TRANSPARENT [$($wrapper_impl:tt)*] [$($derive:ident),*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL_TRANSPARENT_REPR [$($wrapper_impl)*] [$($derive),*] []
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL_TRANSPARENT_REPR [$($wrapper_impl:tt)*] [$($derive:ident),*] [$($outer:tt)*]
+        #[repr(transparent)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL_ZEROCOPY_DERIVE [$($wrapper_impl)*] [$($derive),*]
+            $($outer)*
+            #[repr(transparent)]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL_TRANSPARENT_REPR [$($wrapper_impl:tt)*] [$($derive:ident),*] [$($outer:tt)*]
+        #[$($meta:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL_TRANSPARENT_REPR [$($wrapper_impl)*] [$($derive),*] [$($outer)* #[$($meta)*]]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL_TRANSPARENT_REPR [$($wrapper_impl:tt)*] [$($derive:ident),*] [$($outer:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL_ZEROCOPY_DERIVE [$($wrapper_impl)*] [$($derive),*]
+            #[repr(transparent)]
+            $($outer)*
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL_ZEROCOPY_DERIVE [$($wrapper_impl:tt)*] []
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($wrapper_impl)*
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL_ZEROCOPY_DERIVE [$($wrapper_impl:tt)*] [$($derive:ident),+]
+        $($tt:tt)*
+    ) => {
+        $crate::__cfg_zerocopy_derive! {
+            [$($wrapper_impl)*] [$($derive),+]
+            $($tt)*
+        }
+    };
+
+    // The actual implementation of the wrapper type: `pub Name<...>(...)`
+    (
+        @INTERNAL IMPL
+        #[repr(align(cache))]
+        $(#[$outer:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        // Starting from Intel's Sandy Bridge, spatial prefetcher is now pulling pairs of 64-byte cache
+        // lines at a time, so we have to align to 128 bytes rather than 64.
+        //
+        // Sources:
+        // - https://www.intel.com/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-optimization-manual.pdf
+        // - https://github.com/facebook/folly/blob/1b5288e6eea6df074758f877c849b6e73bbb9fbb/folly/lang/Align.h#L107
+        //
+        // aarch64/arm64ec's big.LITTLE architecture has asymmetric cores and "big" cores have 128-byte cache line size.
+        //
+        // Sources:
+        // - https://www.mono-project.com/news/2016/09/12/arm64-icache/
+        //
+        // powerpc64 has 128-byte cache line size.
+        //
+        // Sources:
+        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_ppc64x.go#L9
+        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/powerpc/include/asm/cache.h#L26
+        #[cfg_attr(
+            any(
+                target_arch = "x86_64",
+                target_arch = "aarch64",
+                target_arch = "arm64ec",
+                target_arch = "powerpc64",
+            ),
+            repr(align(128))
+        )]
+        // arm, mips, mips64, sparc, and hexagon have 32-byte cache line size.
+        //
+        // Sources:
+        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_arm.go#L7
+        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_mips.go#L7
+        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_mipsle.go#L7
+        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_mips64x.go#L9
+        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/sparc/include/asm/cache.h#L17
+        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/hexagon/include/asm/cache.h#L12
+        #[cfg_attr(
+            any(
+                target_arch = "arm",
+                target_arch = "mips",
+                target_arch = "mips32r6",
+                target_arch = "mips64",
+                target_arch = "mips64r6",
+                target_arch = "sparc",
+                target_arch = "hexagon",
+            ),
+            repr(align(32))
+        )]
+        // m68k has 16-byte cache line size.
+        //
+        // Sources:
+        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/m68k/include/asm/cache.h#L9
+        #[cfg_attr(target_arch = "m68k", repr(align(16)))]
+        // s390x has 256-byte cache line size.
+        //
+        // Sources:
+        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_s390x.go#L7
+        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/s390/include/asm/cache.h#L13
+        #[cfg_attr(target_arch = "s390x", repr(align(256)))]
+        // x86, wasm, riscv, and sparc64 have 64-byte cache line size.
+        //
+        // Sources:
+        // - https://github.com/golang/go/blob/dda2991c2ea0c5914714469c4defc2562a907230/src/internal/cpu/cpu_x86.go#L9
+        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_wasm.go#L7
+        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/riscv/include/asm/cache.h#L10
+        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/sparc/include/asm/cache.h#L19
+        //
+        // All others are assumed to have 64-byte cache line size.
+        #[cfg_attr(
+            not(any(
+                target_arch = "x86_64",
+                target_arch = "aarch64",
+                target_arch = "arm64ec",
+                target_arch = "powerpc64",
+                target_arch = "arm",
+                target_arch = "mips",
+                target_arch = "mips32r6",
+                target_arch = "mips64",
+                target_arch = "mips64r6",
+                target_arch = "sparc",
+                target_arch = "hexagon",
+                target_arch = "m68k",
+                target_arch = "s390x",
+            )),
+            repr(align(64))
+        )]
+        $(#[$outer])*
+        $vis struct $name$(<$($lt),+>)? {
+            /// Inner value
+            $inner_vis inner: $inner_ty,
+        }
+    };
+
+    (
+        @INTERNAL IMPL
+        $(#[$outer:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        $(#[$outer])*
+        #[repr(transparent)]
+        $vis struct $name$(<$($lt),+>)? {
+            /// Inner value
+            $inner_vis inner: $inner_ty,
+        }
+    };
+
+    // The actual implementation of the wrapper type: `pub struct Name<...> { ... }`
+    // with field initial value provided, make `const_from` const.
+    (
+        @INTERNAL IMPL
+        #[repr(align(cache))]
+        $(#[$outer:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty = $field_default: expr
+            )*
+            $(,)?
+        }
+    ) => {
+        // Starting from Intel's Sandy Bridge, spatial prefetcher is now pulling pairs of 64-byte cache
+        // lines at a time, so we have to align to 128 bytes rather than 64.
+        //
+        // Sources:
+        // - https://www.intel.com/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-optimization-manual.pdf
+        // - https://github.com/facebook/folly/blob/1b5288e6eea6df074758f877c849b6e73bbb9fbb/folly/lang/Align.h#L107
+        //
+        // aarch64/arm64ec's big.LITTLE architecture has asymmetric cores and "big" cores have 128-byte cache line size.
+        //
+        // Sources:
+        // - https://www.mono-project.com/news/2016/09/12/arm64-icache/
+        //
+        // powerpc64 has 128-byte cache line size.
+        //
+        // Sources:
+        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_ppc64x.go#L9
+        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/powerpc/include/asm/cache.h#L26
+        #[cfg_attr(
+            any(
+                target_arch = "x86_64",
+                target_arch = "aarch64",
+                target_arch = "arm64ec",
+                target_arch = "powerpc64",
+            ),
+            repr(align(128))
+        )]
+        // arm, mips, mips64, sparc, and hexagon have 32-byte cache line size.
+        //
+        // Sources:
+        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_arm.go#L7
+        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_mips.go#L7
+        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_mipsle.go#L7
+        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_mips64x.go#L9
+        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/sparc/include/asm/cache.h#L17
+        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/hexagon/include/asm/cache.h#L12
+        #[cfg_attr(
+            any(
+                target_arch = "arm",
+                target_arch = "mips",
+                target_arch = "mips32r6",
+                target_arch = "mips64",
+                target_arch = "mips64r6",
+                target_arch = "sparc",
+                target_arch = "hexagon",
+            ),
+            repr(align(32))
+        )]
+        // m68k has 16-byte cache line size.
+        //
+        // Sources:
+        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/m68k/include/asm/cache.h#L9
+        #[cfg_attr(target_arch = "m68k", repr(align(16)))]
+        // s390x has 256-byte cache line size.
+        //
+        // Sources:
+        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_s390x.go#L7
+        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/s390/include/asm/cache.h#L13
+        #[cfg_attr(target_arch = "s390x", repr(align(256)))]
+        // x86, wasm, riscv, and sparc64 have 64-byte cache line size.
+        //
+        // Sources:
+        // - https://github.com/golang/go/blob/dda2991c2ea0c5914714469c4defc2562a907230/src/internal/cpu/cpu_x86.go#L9
+        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_wasm.go#L7
+        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/riscv/include/asm/cache.h#L10
+        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/sparc/include/asm/cache.h#L19
+        //
+        // All others are assumed to have 64-byte cache line size.
+        #[cfg_attr(
+            not(any(
+                target_arch = "x86_64",
+                target_arch = "aarch64",
+                target_arch = "arm64ec",
+                target_arch = "powerpc64",
+                target_arch = "arm",
+                target_arch = "mips",
+                target_arch = "mips32r6",
+                target_arch = "mips64",
+                target_arch = "mips64r6",
+                target_arch = "sparc",
+                target_arch = "hexagon",
+                target_arch = "m68k",
+                target_arch = "s390x",
+            )),
+            repr(align(64))
+        )]
+        $(#[$outer])*
+        $vis struct $name$(<$($lt),+>)? {
+            $(#[$field_inner_meta])*
+            $inner_vis $inner: $inner_ty,
+            $(
+                $(#[$field_meta])*
+                $field_vis $field: $field_ty
+            ),*
+        }
+    };
+
+    (
+        @INTERNAL IMPL
+        $(#[$outer:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty = $field_default: expr
+            )*
+            $(,)?
+        }
+    ) => {
+        $(#[$outer])*
+        $vis struct $name$(<$($lt),+>)? {
+            $(#[$field_inner_meta])*
+            $inner_vis $inner: $inner_ty,
+            $(
+                $(#[$field_meta])*
+                $field_vis $field: $field_ty
+            ),*
+        }
+    };
+
+    // The actual implementation of the wrapper type with fields: `pub struct Name<...> { ... }`
+    (
+        @INTERNAL IMPL
+        #[repr(align(cache))]
+        $(#[$outer:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty
+            )*
+            $(,)?
+        }
+    ) => {
+        // Starting from Intel's Sandy Bridge, spatial prefetcher is now pulling pairs of 64-byte cache
+        // lines at a time, so we have to align to 128 bytes rather than 64.
+        //
+        // Sources:
+        // - https://www.intel.com/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-optimization-manual.pdf
+        // - https://github.com/facebook/folly/blob/1b5288e6eea6df074758f877c849b6e73bbb9fbb/folly/lang/Align.h#L107
+        //
+        // aarch64/arm64ec's big.LITTLE architecture has asymmetric cores and "big" cores have 128-byte cache line size.
+        //
+        // Sources:
+        // - https://www.mono-project.com/news/2016/09/12/arm64-icache/
+        //
+        // powerpc64 has 128-byte cache line size.
+        //
+        // Sources:
+        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_ppc64x.go#L9
+        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/powerpc/include/asm/cache.h#L26
+        #[cfg_attr(
+            any(
+                target_arch = "x86_64",
+                target_arch = "aarch64",
+                target_arch = "arm64ec",
+                target_arch = "powerpc64",
+            ),
+            repr(align(128))
+        )]
+        // arm, mips, mips64, sparc, and hexagon have 32-byte cache line size.
+        //
+        // Sources:
+        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_arm.go#L7
+        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_mips.go#L7
+        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_mipsle.go#L7
+        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_mips64x.go#L9
+        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/sparc/include/asm/cache.h#L17
+        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/hexagon/include/asm/cache.h#L12
+        #[cfg_attr(
+            any(
+                target_arch = "arm",
+                target_arch = "mips",
+                target_arch = "mips32r6",
+                target_arch = "mips64",
+                target_arch = "mips64r6",
+                target_arch = "sparc",
+                target_arch = "hexagon",
+            ),
+            repr(align(32))
+        )]
+        // m68k has 16-byte cache line size.
+        //
+        // Sources:
+        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/m68k/include/asm/cache.h#L9
+        #[cfg_attr(target_arch = "m68k", repr(align(16)))]
+        // s390x has 256-byte cache line size.
+        //
+        // Sources:
         // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_s390x.go#L7
         // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/s390/include/asm/cache.h#L13
         #[cfg_attr(target_arch = "s390x", repr(align(256)))]
@@ -486,560 +2505,6737 @@ macro_rules! wrapper {
         )]
         $(#[$outer])*
         $vis struct $name$(<$($lt),+>)? {
-            /// Inner value
-            $inner_vis inner: $inner_ty,
+            $(#[$field_inner_meta])*
+            $inner_vis $inner: $inner_ty
+            $(
+                ,
+                $(#[$field_meta])*
+                $field_vis $field: $field_ty
+            )*
+        }
+    };
+
+    (
+        @INTERNAL IMPL
+        $(#[$outer:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty
+            )*
+            $(,)?
+        }
+    ) => {
+        $(#[$outer])*
+        $vis struct $name$(<$($lt),+>)? {
+            $(#[$field_inner_meta])*
+            $inner_vis $inner: $inner_ty
+            $(
+                ,
+                $(#[$field_meta])*
+                $field_vis $field: $field_ty
+            )*
+        }
+    };
+
+    // Generate `const_from` for the wrapper type, unless the wrapper type is a
+    // validated one (see `TryFrom`).
+    (
+        @INTERNAL IMPL_CONST_FROM
+        #[wrapper_impl(TryFrom($($args:tt)*))]
+        $($tt:tt)*
+    ) => {};
+    (
+        @INTERNAL IMPL_CONST_FROM
+        #[wrapper_impl($($args:tt)*)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL_CONST_FROM
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL_CONST_FROM
+        $(#[$outer:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
+            #[inline(always)]
+            #[doc = concat!("Creates a new instance of [`", stringify!($name), "`]")]
+            $inner_vis const fn const_from(inner: $inner_ty) -> Self {
+                Self {
+                    inner,
+                }
+            }
+        }
+    };
+    (
+        @INTERNAL IMPL_CONST_FROM
+        $(#[$outer:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty = $field_default: expr
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
+            #[inline(always)]
+            #[doc = concat!("Creates a new instance of [`", stringify!($name), "`]")]
+            $inner_vis const fn const_from($inner: $inner_ty) -> Self {
+                Self {
+                    $inner,
+                    $(
+                        $field: $field_default,
+                    )*
+                }
+            }
+        }
+    };
+    // No default values given, cannot generate `const_from`.
+    (@INTERNAL IMPL_CONST_FROM $($tt:tt)*) => {};
+
+    // Generate the pinning guards and the pin projection methods once, if any
+    // `wrapper_impl` requires projecting `Pin<&mut Self>` to the inner field.
+    (
+        @INTERNAL IMPL_PIN_PROJECT
+        #[wrapper_impl(PinProject)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PIN_GUARD
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PIN_PROJECT
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL_PIN_PROJECT
+        #[wrapper_impl(Future)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PIN_GUARD
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PIN_PROJECT
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL_PIN_PROJECT
+        #[wrapper_impl(TokioAsyncRead)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PIN_GUARD
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PIN_PROJECT
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL_PIN_PROJECT
+        #[wrapper_impl(TokioAsyncWrite)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PIN_GUARD
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PIN_PROJECT
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL_PIN_PROJECT
+        #[wrapper_impl(TokioAsyncBufRead)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PIN_GUARD
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PIN_PROJECT
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL_PIN_PROJECT
+        #[wrapper_impl(TokioAsyncSeek)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PIN_GUARD
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PIN_PROJECT
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL_PIN_PROJECT
+        #[wrapper_impl(FuturesAsyncRead)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PIN_GUARD
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PIN_PROJECT
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL_PIN_PROJECT
+        #[wrapper_impl(FuturesAsyncWrite)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PIN_GUARD
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PIN_PROJECT
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL_PIN_PROJECT
+        #[wrapper_impl(FuturesAsyncBufRead)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PIN_GUARD
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PIN_PROJECT
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL_PIN_PROJECT
+        #[wrapper_impl(FuturesAsyncSeek)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PIN_GUARD
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PIN_PROJECT
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL IMPL_PIN_PROJECT
+        #[wrapper_impl($($args:tt)*)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL_PIN_PROJECT
+            $($tt)*
+        }
+    };
+    (@INTERNAL IMPL_PIN_PROJECT $($tt:tt)*) => {};
+
+    // Process all `wrapper_impl` attributes, unless the wrapper type is a
+    // validated one (see `TryFrom`), for which the ones creating the wrapper
+    // type from arbitrary inner values or exposing the inner value mutably are
+    // rejected and skipped first, as well as a public inner field.
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED [$($wrapper_impl:tt)*]
+        #[wrapper_impl(TryFrom($($args:tt)*))]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_FILTER []
+            $($wrapper_impl)*
+            #[wrapper_impl(TryFrom($($args)*))]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED [$($wrapper_impl:tt)*]
+        #[wrapper_impl($($args:tt)*)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED [$($wrapper_impl)* #[wrapper_impl($($args)*)]]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED [$($wrapper_impl:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($wrapper_impl)*
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_FILTER [$($wrapper_impl:tt)*]
+        #[wrapper_impl($attr:ident $($args:tt)*)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT $attr
+            [$($wrapper_impl)*] [#[wrapper_impl($attr $($args)*)]]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_FILTER [$($wrapper_impl:tt)*]
+        $(#[$outer:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? (pub $($inner:tt)*);
+    ) => {
+        $crate::wrapper! { @INTERNAL WRAPPER_IMPL_VALIDATED_PUB }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_FILTER [$($wrapper_impl:tt)*]
+        $(#[$outer:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            pub $($fields:tt)*
+        }
+    ) => {
+        $crate::wrapper! { @INTERNAL WRAPPER_IMPL_VALIDATED_PUB }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_FILTER [$($wrapper_impl:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($wrapper_impl)*
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT From
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT From
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT FromStr
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT FromStr
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT FromIterator
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT FromIterator
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Sum
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Sum
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Product
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Product
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Deserialize
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Deserialize
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Add
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Add
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Sub
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Sub
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Mul
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Mul
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Div
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Div
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Rem
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Rem
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT BitAnd
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT BitAnd
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT BitOr
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT BitOr
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT BitXor
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT BitXor
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Shl
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Shl
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Shr
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Shr
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Neg
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Neg
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Not
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Not
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT AddAssign
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT AddAssign
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT SubAssign
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT SubAssign
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT MulAssign
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT MulAssign
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT DivAssign
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT DivAssign
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT RemAssign
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT RemAssign
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT BitAndAssign
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT BitAndAssign
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT BitOrAssign
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT BitOrAssign
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT BitXorAssign
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT BitXorAssign
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT ShlAssign
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT ShlAssign
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT ShrAssign
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT ShrAssign
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Default
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Default
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Zeroable
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Zeroable
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Pod
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Pod
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT TransparentWrapper
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT TransparentWrapper
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Zerocopy
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Zerocopy
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT RefCast
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT RefCast
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT AsMut
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT AsMut
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT ConstAsMut
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT ConstAsMut
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT BorrowMut
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT BorrowMut
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT DerefMut
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT DerefMut
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT IndexMut
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT IndexMut
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Extend
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Extend
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT IntoIterator
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT IntoIterator
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Iterator
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Iterator
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT DoubleEndedIterator
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT DoubleEndedIterator
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Hasher
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Hasher
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Read
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Read
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Write
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Write
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Seek
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Seek
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT BufRead
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT BufRead
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT FmtWrite
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT FmtWrite
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT Future
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT Future
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT PinProject
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT PinProject
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT TokioAsyncRead
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT TokioAsyncRead
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT TokioAsyncWrite
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT TokioAsyncWrite
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT TokioAsyncBufRead
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT TokioAsyncBufRead
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT TokioAsyncSeek
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT TokioAsyncSeek
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT FuturesAsyncRead
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT FuturesAsyncRead
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT FuturesAsyncWrite
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT FuturesAsyncWrite
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT FuturesAsyncBufRead
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT FuturesAsyncBufRead
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT FuturesAsyncSeek
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT FuturesAsyncSeek
+            [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_CONFLICT $attr:ident
+        [$($wrapper_impl:tt)*] [$($current:tt)*]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_FILTER [$($wrapper_impl)* $($current)*]
+            $($tt)*
+        }
+    };
+    (@INTERNAL WRAPPER_IMPL_VALIDATED_PUB) => {
+        compile_error!(
+            "Invalid usage of `wrapper!` macro, the inner field of wrapper types \
+            with `TryFrom` cannot be public, which would bypass the validation."
+        );
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_VALIDATED_REJECT $attr:ident
+        [$($wrapper_impl:tt)*]
+        $($tt:tt)*
+    ) => {
+        compile_error!(::core::concat!(
+            "Invalid usage of `wrapper!` macro, `",
+            ::core::stringify!($attr),
+            "` cannot be used together with `TryFrom`, which would bypass the validation."
+        ));
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_VALIDATED_FILTER [$($wrapper_impl)*]
+            $($tt)*
+        }
+    };
+
+    // === Process all `wrapper_impl` attributes, and generate impls. ===
+
+    // Extract wrapper impl for `AsRef` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(AsRef $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_AS_REF $(<$target>)?
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `AsMut` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(AsMut $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_AS_MUT $(<$target>)?
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `AsMut` trait, const version.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(ConstAsMut $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_CONST_AS_MUT $(<$target>)?
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Borrow` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Borrow $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_BORROW $(<$target>)?
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `BorrowMut` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(BorrowMut $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_BORROW $(<$target>)?
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_BORROW_MUT $(<$target>)?
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Debug` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Debug)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_DEBUG
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Debug` trait  printing its name only.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(DebugName)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_DEBUG_NAME
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Display` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Display)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_DISPLAY
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `LowerHex` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(LowerHex)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FMT LowerHex
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `UpperHex` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(UpperHex)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FMT UpperHex
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Binary` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Binary)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FMT Binary
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Octal` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Octal)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FMT Octal
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `LowerExp` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(LowerExp)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FMT LowerExp
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `UpperExp` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(UpperExp)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FMT UpperExp
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Pointer` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Pointer)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FMT Pointer
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for all numeric formatting traits.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(NumericFmt)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FMT LowerHex
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FMT UpperHex
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FMT Binary
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FMT Octal
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FMT LowerExp
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FMT UpperExp
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `PartialEq` trait, with a reference type with
+    // elided lifetime, which is not allowed in where clauses.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(PartialEq<&$cmp_lt:lifetime $target:ty>)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PARTIAL_EQ [] <&$cmp_lt $target>
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(PartialEq<&$target:ty>)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PARTIAL_EQ ['__cmp] <&'__cmp $target>
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `PartialEq` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(PartialEq $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PARTIAL_EQ $([] <$target>)?
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Eq` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Eq)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_EQ
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `PartialOrd` trait, with a reference type with
+    // elided lifetime, which is not allowed in where clauses.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(PartialOrd<&$cmp_lt:lifetime $target:ty>)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PARTIAL_ORD [] <&$cmp_lt $target>
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(PartialOrd<&$target:ty>)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PARTIAL_ORD ['__cmp] <&'__cmp $target>
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `PartialOrd` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(PartialOrd $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PARTIAL_ORD $([] <$target>)?
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Ord` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Ord)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_ORD
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Hash` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Hash)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_HASH
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Clone` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Clone)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_CLONE
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Copy` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Copy)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_COPY
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Deref` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Deref $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_DEREF $(<$target>)?
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `DerefMut` trait (and `Deref`).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(DerefMut $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_DEREF $(<$target>)?
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_DEREF_MUT $(<$target>)?
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `From` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(From)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FROM
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `FromStr` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(FromStr)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FROM_STR
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Default` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Default)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_DEFAULT
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `IntoIterator` trait (for `W`, `&W` and `&mut W`).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(IntoIterator)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_INTO_ITERATOR
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `FromIterator` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(FromIterator)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FROM_ITERATOR
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Extend` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Extend)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_EXTEND
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Index` trait, with a reference type with
+    // elided lifetime, which is not allowed in where clauses.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Index<&$idx_lt:lifetime $target:ty>)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_INDEX [] <&$idx_lt $target>
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Index<&$target:ty>)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_INDEX ['__idx] <&'__idx $target>
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Index` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Index $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_INDEX $([] <$target>)?
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `IndexMut` trait (and `Index`), with a reference type with
+    // elided lifetime, which is not allowed in where clauses.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(IndexMut<&$idx_lt:lifetime $target:ty>)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_INDEX [] <&$idx_lt $target>
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_INDEX_MUT [] <&$idx_lt $target>
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(IndexMut<&$target:ty>)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_INDEX ['__idx] <&'__idx $target>
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_INDEX_MUT ['__idx] <&'__idx $target>
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `IndexMut` trait (and `Index`).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(IndexMut $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_INDEX $([] <$target>)?
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_INDEX_MUT $([] <$target>)?
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Sum` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Sum)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_ITER_FOLD Sum sum
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Product` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Product)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_ITER_FOLD Product product
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Iterator` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Iterator)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_ITERATOR
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `DoubleEndedIterator` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(DoubleEndedIterator)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_DOUBLE_ENDED_ITERATOR
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `ExactSizeIterator` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(ExactSizeIterator)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_EXACT_SIZE_ITERATOR
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `FusedIterator` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(FusedIterator)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FUSED_ITERATOR
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Error` trait (requires the `std` feature).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Error)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_ERROR
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Hasher` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Hasher)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_HASHER
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `BuildHasher` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(BuildHasher $(<$target:ty>)? )]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_BUILD_HASHER $(<$target>)?
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `io::Read` trait (requires the `std` feature).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Read)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_IO_READ
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `io::Write` trait (requires the `std` feature).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Write)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_IO_WRITE
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `io::Seek` trait (requires the `std` feature).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Seek)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_IO_SEEK
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `io::BufRead` trait (requires the `std` feature).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(BufRead)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_IO_BUF_READ
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `fmt::Write` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(FmtWrite)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FMT_WRITE
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // `PinProject` is handled by the `IMPL_PIN_PROJECT` pass.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(PinProject)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Future` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Future)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FUTURE
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `tokio::io::AsyncRead` trait (requires the `tokio` feature).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(TokioAsyncRead)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_TOKIO_ASYNC_READ
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `tokio::io::AsyncWrite` trait (requires the `tokio` feature).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(TokioAsyncWrite)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_TOKIO_ASYNC_WRITE
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `tokio::io::AsyncBufRead` trait (requires the `tokio` feature).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(TokioAsyncBufRead)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_TOKIO_ASYNC_BUF_READ
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `tokio::io::AsyncSeek` trait (requires the `tokio` feature).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(TokioAsyncSeek)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_TOKIO_ASYNC_SEEK
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `futures_io::AsyncRead` trait (requires the `futures-io` feature).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(FuturesAsyncRead)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FUTURES_ASYNC_READ
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `futures_io::AsyncWrite` trait (requires the `futures-io` feature).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(FuturesAsyncWrite)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FUTURES_ASYNC_WRITE
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `futures_io::AsyncBufRead` trait (requires the `futures-io` feature).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(FuturesAsyncBufRead)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FUTURES_ASYNC_BUF_READ
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `futures_io::AsyncSeek` trait (requires the `futures-io` feature).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(FuturesAsyncSeek)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_FUTURES_ASYNC_SEEK
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `serde::Serialize` trait (requires the `serde` feature).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Serialize)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_SERDE_SERIALIZE
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `serde::Deserialize` trait (requires the `serde` feature).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Deserialize)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_SERDE_DESERIALIZE
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `serde::Serialize` and `serde::Deserialize` traits via `Display` and `FromStr` (requires the `serde` feature).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(SerdeAsString)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_SERDE_AS_STRING
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `bytemuck::Zeroable` trait (requires the `bytemuck` feature).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Zeroable)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_CHECK_TRANSPARENT Zeroable
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `bytemuck::Pod` trait (and `bytemuck::Zeroable`, requires the `bytemuck` feature).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Pod)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_CHECK_TRANSPARENT Zeroable
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_CHECK_TRANSPARENT Pod
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `bytemuck::TransparentWrapper` trait (requires the `bytemuck` feature).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(TransparentWrapper)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_CHECK_TRANSPARENT TransparentWrapper
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `zerocopy` traits, the derives are added in the `IMPL` pass (requires the `zerocopy` feature).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Zerocopy)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_CHECK_TRANSPARENT Zerocopy
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `zerocopy::Unaligned` trait, the derive is added in the `IMPL` pass (requires the `zerocopy` feature).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Unaligned)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_CHECK_TRANSPARENT Unaligned
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `from_ref` and `from_mut` methods.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(RefCast)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_CHECK_TRANSPARENT RefCast
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `From<Wrapper>` trait for the inner type (and `into_inner`).
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Into)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_INTO_INNER
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_INTO
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `into_inner` method.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(IntoInner)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_INTO_INNER
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `TryFrom` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(TryFrom(validate = $validate:path $(, error = $error:ty)? $(,)?))]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_TRY_FROM [$validate] $([$error])?
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Add` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Add)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_BINARY Add add
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `AddAssign` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(AddAssign)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_ASSIGN AddAssign add_assign
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Sub` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Sub)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_BINARY Sub sub
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `SubAssign` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(SubAssign)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_ASSIGN SubAssign sub_assign
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Mul` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Mul)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_BINARY Mul mul
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `MulAssign` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(MulAssign)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_ASSIGN MulAssign mul_assign
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Div` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Div)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_BINARY Div div
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `DivAssign` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(DivAssign)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_ASSIGN DivAssign div_assign
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Rem` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Rem)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_BINARY Rem rem
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `RemAssign` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(RemAssign)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_ASSIGN RemAssign rem_assign
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Neg` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Neg)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_UNARY Neg neg
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `BitAnd` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(BitAnd)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_BINARY BitAnd bitand
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `BitAndAssign` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(BitAndAssign)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_ASSIGN BitAndAssign bitand_assign
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `BitOr` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(BitOr)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_BINARY BitOr bitor
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `BitOrAssign` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(BitOrAssign)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_ASSIGN BitOrAssign bitor_assign
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `BitXor` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(BitXor)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_BINARY BitXor bitxor
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `BitXorAssign` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(BitXorAssign)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_ASSIGN BitXorAssign bitxor_assign
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Not` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Not)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_UNARY Not not
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Shl` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Shl)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_SHIFT Shl shl
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `ShlAssign` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(ShlAssign)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_SHIFT_ASSIGN ShlAssign shl_assign
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `Shr` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(Shr)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_SHIFT Shr shr
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // Extract wrapper impl for `ShrAssign` trait.
+    (
+        @INTERNAL WRAPPER_IMPL
+        #[wrapper_impl(ShrAssign)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_SHIFT_ASSIGN ShrAssign shr_assign
+            $($tt)*
+        }
+
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL
+            $($tt)*
+        }
+    };
+
+    // ================ Impl `AsRef` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_AS_REF <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsRef<$target> for $name$(<$($lt),+>)? {
+            fn as_ref(&self) -> &$target {
+                &self.inner
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_AS_REF <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsRef<$target> for $name$(<$($lt),+>)? {
+            fn as_ref(&self) -> &$target {
+                &self.$inner
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_AS_REF
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsRef<$inner_ty> for $name$(<$($lt),+>)? {
+            fn as_ref(&self) -> &$inner_ty {
+                &self.inner
+            }
+        }
+
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
+            /// Returns a reference to the inner value.
+            #[inline(always)]
+            pub const fn as_inner(&self) -> &$inner_ty {
+                &self.inner
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_AS_REF
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsRef<$inner_ty> for $name$(<$($lt),+>)? {
+            fn as_ref(&self) -> &$inner_ty {
+                &self.$inner
+            }
+        }
+
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
+            /// Returns a reference to the inner value.
+            #[inline(always)]
+            pub const fn as_inner(&self) -> &$inner_ty {
+                &self.$inner
+            }
+        }
+    };
+    // ================ Impl `AsRef` trait for the wrapper type. ================
+
+
+    // ================ Impl `AsMut` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_AS_MUT <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsMut<$target> for $name$(<$($lt),+>)? {
+            fn as_mut(&mut self) -> &mut $target {
+                &mut self.inner
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_AS_MUT <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsMut<$target> for $name$(<$($lt),+>)? {
+            #[inline(always)]
+            fn as_mut(&mut self) -> &mut $target {
+                &mut self.$inner
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_AS_MUT
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsMut<$inner_ty> for $name$(<$($lt),+>)? {
+            fn as_mut(&mut self) -> &mut $inner_ty {
+                &mut self.inner
+            }
+        }
+
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
+            #[inline(always)]
+            /// Returns a mutable reference to the inner value.
+            pub fn as_inner_mut(&mut self) -> &mut $inner_ty {
+                &mut self.inner
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_AS_MUT
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsMut<$inner_ty> for $name$(<$($lt),+>)? {
+            #[inline(always)]
+            fn as_mut(&mut self) -> &mut $inner_ty {
+                &mut self.$inner
+            }
+        }
+
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
+            #[inline(always)]
+            /// Returns a mutable reference to the inner value.
+            pub fn as_inner_mut(&mut self) -> &mut $inner_ty {
+                &mut self.$inner
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_CONST_AS_MUT <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsMut<$target> for $name$(<$($lt),+>)? {
+            fn as_mut(&mut self) -> &mut $target {
+                &mut self.inner
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_CONST_AS_MUT
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsMut<$target> for $name$(<$($lt),+>)? {
+            #[inline(always)]
+            fn as_mut(&mut self) -> &mut $target {
+                &mut self.$inner
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_CONST_AS_MUT
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsMut<$inner_ty> for $name$(<$($lt),+>)? {
+            fn as_mut(&mut self) -> &mut $inner_ty {
+                &mut self.inner
+            }
+        }
+
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
+            #[inline(always)]
+            /// Returns a mutable reference to the inner value.
+            pub const fn as_inner_mut(&mut self) -> &mut $inner_ty {
+                &mut self.inner
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_CONST_AS_MUT
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsMut<$inner_ty> for $name$(<$($lt),+>)? {
+            #[inline(always)]
+            fn as_mut(&mut self) -> &mut $inner_ty {
+                &mut self.$inner
+            }
+        }
+
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
+            #[inline(always)]
+            /// Returns a mutable reference to the inner value.
+            pub const fn as_inner_mut(&mut self) -> &mut $inner_ty {
+                &mut self.$inner
+            }
+        }
+    };
+    // ================ Impl `AsMut` trait for the wrapper type. ================
+
+    // ================ Impl `Borrow` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_BORROW <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::borrow::Borrow<$target> for $name$(<$($lt),+>)? {
+            fn borrow(&self) -> &$target {
+                &self.inner
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_BORROW <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::borrow::Borrow<$target> for $name$(<$($lt),+>)? {
+            fn borrow(&self) -> &$target {
+                &self.$inner
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_BORROW
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::borrow::Borrow<$inner_ty> for $name$(<$($lt),+>)? {
+            fn borrow(&self) -> &$inner_ty {
+                &self.inner
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_BORROW
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::borrow::Borrow<$inner_ty> for $name$(<$($lt),+>)? {
+            fn borrow(&self) -> &$inner_ty {
+                &self.$inner
+            }
+        }
+    };
+    // ================ Impl `Borrow` trait for the wrapper type. ================
+
+    // ================ Impl `BorrowMut` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_BORROW_MUT <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::borrow::BorrowMut<$target> for $name$(<$($lt),+>)? {
+            fn borrow_mut(&mut self) -> &mut $target {
+                &mut self.inner
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_BORROW_MUT <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::borrow::BorrowMut<$target> for $name$(<$($lt),+>)? {
+            fn borrow_mut(&mut self) -> &mut $target {
+                &mut self.$inner
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_BORROW_MUT
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::borrow::BorrowMut<$inner_ty> for $name$(<$($lt),+>)? {
+            fn borrow_mut(&mut self) -> &mut $inner_ty {
+                &mut self.inner
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_BORROW_MUT
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::borrow::BorrowMut<$inner_ty> for $name$(<$($lt),+>)? {
+            fn borrow_mut(&mut self) -> &mut $inner_ty {
+                &mut self.$inner
+            }
+        }
+    };
+    // ================ Impl `Borrow` trait for the wrapper type. ================
+
+    // ================ Impl `Debug` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_DEBUG
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::fmt::Debug for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                self.inner.fmt(f)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_DEBUG
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::fmt::Debug for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                self.$inner.fmt(f)
+            }
+        }
+    };
+    // ================ Impl `Debug` trait for the wrapper type. ================
+
+    // ================ Impl `DebugName` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_DEBUG_NAME
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::fmt::Debug for $name$(<$($lt),+>)? {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(stringify!($name)).finish()
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_DEBUG_NAME
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::fmt::Debug for $name$(<$($lt),+>)? {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(stringify!($name)).finish()
+            }
+        }
+    };
+    // ================ Impl `DebugName` trait for the wrapper type. ================
+
+    // ================ Impl `Display` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_DISPLAY
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::fmt::Display for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::fmt::Display,
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                self.inner.fmt(f)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_DISPLAY
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::fmt::Display for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::fmt::Display,
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                self.$inner.fmt(f)
+            }
+        }
+    };
+    // ================ Impl `Display` trait for the wrapper type. ================
+
+    // ================ Impl other formatting traits for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_FMT $trait:ident
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::fmt::$trait for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::fmt::$trait,
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::$trait::fmt(&self.inner, f)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_FMT $trait:ident
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::fmt::$trait for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::fmt::$trait,
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::$trait::fmt(&self.$inner, f)
+            }
+        }
+    };
+    // ================ Impl other formatting traits for the wrapper type. ================
+
+    // ================ Impl `PartialEq` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_PARTIAL_EQ [$($cmp_lt:lifetime)?] <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl<$($cmp_lt,)? $($($lt$(:$clt$(+$dlt)*)?),+)?> ::core::cmp::PartialEq<$target> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::cmp::PartialEq<$target>,
+        {
+            #[inline(always)]
+            fn eq(&self, other: &$target) -> bool {
+                ::core::cmp::PartialEq::eq(&self.inner, other)
+            }
+        }
+
+        impl<$($cmp_lt,)? $($($lt$(:$clt$(+$dlt)*)?),+)?> ::core::cmp::PartialEq<$name$(<$($lt),+>)?> for $target
+        where
+            $target: ::core::cmp::PartialEq<$inner_ty>,
+        {
+            #[inline(always)]
+            fn eq(&self, other: &$name$(<$($lt),+>)?) -> bool {
+                ::core::cmp::PartialEq::eq(self, &other.inner)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_PARTIAL_EQ [$($cmp_lt:lifetime)?] <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl<$($cmp_lt,)? $($($lt$(:$clt$(+$dlt)*)?),+)?> ::core::cmp::PartialEq<$target> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::cmp::PartialEq<$target>,
+        {
+            #[inline(always)]
+            fn eq(&self, other: &$target) -> bool {
+                ::core::cmp::PartialEq::eq(&self.$inner, other)
+            }
+        }
+
+        impl<$($cmp_lt,)? $($($lt$(:$clt$(+$dlt)*)?),+)?> ::core::cmp::PartialEq<$name$(<$($lt),+>)?> for $target
+        where
+            $target: ::core::cmp::PartialEq<$inner_ty>,
+        {
+            #[inline(always)]
+            fn eq(&self, other: &$name$(<$($lt),+>)?) -> bool {
+                ::core::cmp::PartialEq::eq(self, &other.$inner)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_PARTIAL_EQ
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::cmp::PartialEq for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::cmp::PartialEq,
+        {
+            #[inline(always)]
+            fn eq(&self, other: &Self) -> bool {
+                ::core::cmp::PartialEq::eq(&self.inner, &other.inner)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_PARTIAL_EQ
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::cmp::PartialEq for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::cmp::PartialEq,
+        {
+            #[inline(always)]
+            fn eq(&self, other: &Self) -> bool {
+                ::core::cmp::PartialEq::eq(&self.$inner, &other.$inner)
+            }
+        }
+    };
+    // ================ Impl `PartialEq` trait for the wrapper type. ================
+
+    // ================ Impl `Eq` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_EQ
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::cmp::Eq for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::cmp::Eq,
+        {}
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_EQ
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::cmp::Eq for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::cmp::Eq,
+        {}
+    };
+    // ================ Impl `Eq` trait for the wrapper type. ================
+
+    // ================ Impl `PartialOrd` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_PARTIAL_ORD [$($cmp_lt:lifetime)?] <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl<$($cmp_lt,)? $($($lt$(:$clt$(+$dlt)*)?),+)?> ::core::cmp::PartialOrd<$target> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::cmp::PartialOrd<$target>,
+        {
+            #[inline(always)]
+            fn partial_cmp(&self, other: &$target) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::cmp::PartialOrd::partial_cmp(&self.inner, other)
+            }
+        }
+
+        impl<$($cmp_lt,)? $($($lt$(:$clt$(+$dlt)*)?),+)?> ::core::cmp::PartialOrd<$name$(<$($lt),+>)?> for $target
+        where
+            $target: ::core::cmp::PartialOrd<$inner_ty>,
+        {
+            #[inline(always)]
+            fn partial_cmp(&self, other: &$name$(<$($lt),+>)?) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::cmp::PartialOrd::partial_cmp(self, &other.inner)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_PARTIAL_ORD [$($cmp_lt:lifetime)?] <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl<$($cmp_lt,)? $($($lt$(:$clt$(+$dlt)*)?),+)?> ::core::cmp::PartialOrd<$target> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::cmp::PartialOrd<$target>,
+        {
+            #[inline(always)]
+            fn partial_cmp(&self, other: &$target) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::cmp::PartialOrd::partial_cmp(&self.$inner, other)
+            }
+        }
+
+        impl<$($cmp_lt,)? $($($lt$(:$clt$(+$dlt)*)?),+)?> ::core::cmp::PartialOrd<$name$(<$($lt),+>)?> for $target
+        where
+            $target: ::core::cmp::PartialOrd<$inner_ty>,
+        {
+            #[inline(always)]
+            fn partial_cmp(&self, other: &$name$(<$($lt),+>)?) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::cmp::PartialOrd::partial_cmp(self, &other.$inner)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_PARTIAL_ORD
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::cmp::PartialOrd for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::cmp::PartialOrd,
+        {
+            #[inline(always)]
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::cmp::PartialOrd::partial_cmp(&self.inner, &other.inner)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_PARTIAL_ORD
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::cmp::PartialOrd for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::cmp::PartialOrd,
+        {
+            #[inline(always)]
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::cmp::PartialOrd::partial_cmp(&self.$inner, &other.$inner)
+            }
+        }
+    };
+    // ================ Impl `PartialOrd` trait for the wrapper type. ================
+
+    // ================ Impl `Ord` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_ORD
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::cmp::Ord for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::cmp::Ord,
+        {
+            #[inline(always)]
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                ::core::cmp::Ord::cmp(&self.inner, &other.inner)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_ORD
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::cmp::Ord for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::cmp::Ord,
+        {
+            #[inline(always)]
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                ::core::cmp::Ord::cmp(&self.$inner, &other.$inner)
+            }
+        }
+    };
+    // ================ Impl `Ord` trait for the wrapper type. ================
+
+    // ================ Impl `Hash` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_HASH
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::hash::Hash for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::hash::Hash,
+        {
+            #[inline(always)]
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                ::core::hash::Hash::hash(&self.inner, state)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_HASH
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::hash::Hash for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::hash::Hash,
+        {
+            #[inline(always)]
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                ::core::hash::Hash::hash(&self.$inner, state)
+            }
+        }
+    };
+    // ================ Impl `Hash` trait for the wrapper type. ================
+
+    // ================ Impl `Hasher` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_HASHER
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::hash::Hasher for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::hash::Hasher,
+        {
+            #[inline(always)]
+            fn finish(&self) -> u64 {
+                ::core::hash::Hasher::finish(&self.inner)
+            }
+
+            #[inline(always)]
+            fn write(&mut self, bytes: &[u8]) {
+                ::core::hash::Hasher::write(&mut self.inner, bytes)
+            }
+
+            #[inline(always)]
+            fn write_u8(&mut self, i: u8) {
+                ::core::hash::Hasher::write_u8(&mut self.inner, i)
+            }
+
+            #[inline(always)]
+            fn write_u16(&mut self, i: u16) {
+                ::core::hash::Hasher::write_u16(&mut self.inner, i)
+            }
+
+            #[inline(always)]
+            fn write_u32(&mut self, i: u32) {
+                ::core::hash::Hasher::write_u32(&mut self.inner, i)
+            }
+
+            #[inline(always)]
+            fn write_u64(&mut self, i: u64) {
+                ::core::hash::Hasher::write_u64(&mut self.inner, i)
+            }
+
+            #[inline(always)]
+            fn write_u128(&mut self, i: u128) {
+                ::core::hash::Hasher::write_u128(&mut self.inner, i)
+            }
+
+            #[inline(always)]
+            fn write_usize(&mut self, i: usize) {
+                ::core::hash::Hasher::write_usize(&mut self.inner, i)
+            }
+
+            #[inline(always)]
+            fn write_i8(&mut self, i: i8) {
+                ::core::hash::Hasher::write_i8(&mut self.inner, i)
+            }
+
+            #[inline(always)]
+            fn write_i16(&mut self, i: i16) {
+                ::core::hash::Hasher::write_i16(&mut self.inner, i)
+            }
+
+            #[inline(always)]
+            fn write_i32(&mut self, i: i32) {
+                ::core::hash::Hasher::write_i32(&mut self.inner, i)
+            }
+
+            #[inline(always)]
+            fn write_i64(&mut self, i: i64) {
+                ::core::hash::Hasher::write_i64(&mut self.inner, i)
+            }
+
+            #[inline(always)]
+            fn write_i128(&mut self, i: i128) {
+                ::core::hash::Hasher::write_i128(&mut self.inner, i)
+            }
+
+            #[inline(always)]
+            fn write_isize(&mut self, i: isize) {
+                ::core::hash::Hasher::write_isize(&mut self.inner, i)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_HASHER
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::hash::Hasher for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::hash::Hasher,
+        {
+            #[inline(always)]
+            fn finish(&self) -> u64 {
+                ::core::hash::Hasher::finish(&self.$inner)
+            }
+
+            #[inline(always)]
+            fn write(&mut self, bytes: &[u8]) {
+                ::core::hash::Hasher::write(&mut self.$inner, bytes)
+            }
+
+            #[inline(always)]
+            fn write_u8(&mut self, i: u8) {
+                ::core::hash::Hasher::write_u8(&mut self.$inner, i)
+            }
+
+            #[inline(always)]
+            fn write_u16(&mut self, i: u16) {
+                ::core::hash::Hasher::write_u16(&mut self.$inner, i)
+            }
+
+            #[inline(always)]
+            fn write_u32(&mut self, i: u32) {
+                ::core::hash::Hasher::write_u32(&mut self.$inner, i)
+            }
+
+            #[inline(always)]
+            fn write_u64(&mut self, i: u64) {
+                ::core::hash::Hasher::write_u64(&mut self.$inner, i)
+            }
+
+            #[inline(always)]
+            fn write_u128(&mut self, i: u128) {
+                ::core::hash::Hasher::write_u128(&mut self.$inner, i)
+            }
+
+            #[inline(always)]
+            fn write_usize(&mut self, i: usize) {
+                ::core::hash::Hasher::write_usize(&mut self.$inner, i)
+            }
+
+            #[inline(always)]
+            fn write_i8(&mut self, i: i8) {
+                ::core::hash::Hasher::write_i8(&mut self.$inner, i)
+            }
+
+            #[inline(always)]
+            fn write_i16(&mut self, i: i16) {
+                ::core::hash::Hasher::write_i16(&mut self.$inner, i)
+            }
+
+            #[inline(always)]
+            fn write_i32(&mut self, i: i32) {
+                ::core::hash::Hasher::write_i32(&mut self.$inner, i)
+            }
+
+            #[inline(always)]
+            fn write_i64(&mut self, i: i64) {
+                ::core::hash::Hasher::write_i64(&mut self.$inner, i)
+            }
+
+            #[inline(always)]
+            fn write_i128(&mut self, i: i128) {
+                ::core::hash::Hasher::write_i128(&mut self.$inner, i)
+            }
+
+            #[inline(always)]
+            fn write_isize(&mut self, i: isize) {
+                ::core::hash::Hasher::write_isize(&mut self.$inner, i)
+            }
+        }
+    };
+    // ================ Impl `Hasher` trait for the wrapper type. ================
+
+    // ================ Impl `BuildHasher` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_BUILD_HASHER <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::hash::BuildHasher for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::hash::BuildHasher,
+            $target: ::core::convert::From<<$inner_ty as ::core::hash::BuildHasher>::Hasher>,
+        {
+            type Hasher = $target;
+
+            #[inline(always)]
+            fn build_hasher(&self) -> Self::Hasher {
+                ::core::convert::From::from(::core::hash::BuildHasher::build_hasher(&self.inner))
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_BUILD_HASHER <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::hash::BuildHasher for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::hash::BuildHasher,
+            $target: ::core::convert::From<<$inner_ty as ::core::hash::BuildHasher>::Hasher>,
+        {
+            type Hasher = $target;
+
+            #[inline(always)]
+            fn build_hasher(&self) -> Self::Hasher {
+                ::core::convert::From::from(::core::hash::BuildHasher::build_hasher(&self.$inner))
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_BUILD_HASHER
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::hash::BuildHasher for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::hash::BuildHasher,
+        {
+            type Hasher = <$inner_ty as ::core::hash::BuildHasher>::Hasher;
+
+            #[inline(always)]
+            fn build_hasher(&self) -> Self::Hasher {
+                ::core::hash::BuildHasher::build_hasher(&self.inner)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_BUILD_HASHER
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::hash::BuildHasher for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::hash::BuildHasher,
+        {
+            type Hasher = <$inner_ty as ::core::hash::BuildHasher>::Hasher;
+
+            #[inline(always)]
+            fn build_hasher(&self) -> Self::Hasher {
+                ::core::hash::BuildHasher::build_hasher(&self.$inner)
+            }
+        }
+    };
+    // ================ Impl `BuildHasher` trait for the wrapper type. ================
+
+    // ================ Impl `Clone` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_CLONE
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::clone::Clone for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::clone::Clone,
+        {
+            #[inline(always)]
+            fn clone(&self) -> Self {
+                Self {
+                    inner: ::core::clone::Clone::clone(&self.inner),
+                }
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_CLONE
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::clone::Clone for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::clone::Clone,
+            $($field_ty: ::core::clone::Clone,)*
+        {
+            #[inline(always)]
+            fn clone(&self) -> Self {
+                Self {
+                    $inner: ::core::clone::Clone::clone(&self.$inner),
+                    $(
+                        $field: ::core::clone::Clone::clone(&self.$field),
+                    )*
+                }
+            }
+        }
+    };
+    // ================ Impl `Clone` trait for the wrapper type. ================
+
+    // ================ Impl `Copy` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_COPY
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::marker::Copy for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::marker::Copy,
+        {}
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_COPY
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::marker::Copy for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::marker::Copy,
+            $($field_ty: ::core::marker::Copy,)*
+        {}
+    };
+    // ================ Impl `Copy` trait for the wrapper type. ================
+
+    // ================ Impl `Deref` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_DEREF <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::ops::Deref for $name$(<$($lt),+>)? {
+            type Target = $target;
+
+            fn deref(&self) -> &Self::Target {
+                &self.inner
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_DEREF <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::ops::Deref for $name$(<$($lt),+>)? {
+            type Target = $target;
+
+            fn deref(&self) -> &Self::Target {
+                &self.$inner
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_DEREF
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::ops::Deref for $name$(<$($lt),+>)? {
+            type Target = $inner_ty;
+
+            fn deref(&self) -> &Self::Target {
+                &self.inner
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_DEREF
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::ops::Deref for $name$(<$($lt),+>)? {
+            type Target = $inner_ty;
+
+            fn deref(&self) -> &Self::Target {
+                &self.$inner
+            }
+        }
+    };
+    // ================ Impl `Deref` trait for the wrapper type. ================
+
+    // ================ Impl `DerefMut` traits for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_DEREF_MUT <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::ops::DerefMut for $name$(<$($lt),+>)? {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.inner
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_DEREF_MUT <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::ops::DerefMut for $name$(<$($lt),+>)? {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.$inner
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_DEREF_MUT
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::ops::DerefMut for $name$(<$($lt),+>)? {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.inner
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_DEREF_MUT
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::ops::DerefMut for $name$(<$($lt),+>)? {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.$inner
+            }
+        }
+    };
+    // ================ Impl `DerefMut` traits for the wrapper type. ================
+
+    // ================ Impl `From` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_FROM
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::From<$inner_ty> for $name$(<$($lt),+>)? {
+            fn from(inner: $inner_ty) -> Self {
+                Self::const_from(inner)
+            }
+        }
+
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
+            /// Creates a new instance of the wrapper type from the inner value.
+            #[allow(unreachable_pub)]
+            #[inline(always)]
+            pub const fn from(inner: $inner_ty) -> Self {
+                Self::const_from(inner)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_FROM
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty = $field_default:expr
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::From<$inner_ty> for $name$(<$($lt),+>)? {
+            fn from($inner: $inner_ty) -> Self {
+                Self::const_from($inner)
+            }
+        }
+
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
+            /// Creates a new instance of the wrapper type from the inner value.
+            #[allow(unreachable_pub)]
+            #[inline(always)]
+            pub const fn from($inner: $inner_ty) -> Self {
+                Self::const_from($inner)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_FROM
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty
+            )*
+            $(,)?
+        }
+    ) => {
+        compile_error!(
+            "Invalid usage of `wrapper!` macro, cannot implement \
+            `From` trait for wrapper types with multiple fields\
+            but no default values given."
+        );
+    };
+    // ================ Impl `From` trait for the wrapper type. ================
+
+    // ================ Impl `FromStr` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_FROM_STR
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::str::FromStr for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::str::FromStr,
+        {
+            type Err = <$inner_ty as ::core::str::FromStr>::Err;
+
+            #[inline(always)]
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                <$inner_ty as ::core::str::FromStr>::from_str(s).map(Self::const_from)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_FROM_STR
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty = $field_default:expr
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::str::FromStr for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::str::FromStr,
+        {
+            type Err = <$inner_ty as ::core::str::FromStr>::Err;
+
+            #[inline(always)]
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                <$inner_ty as ::core::str::FromStr>::from_str(s).map(Self::const_from)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_FROM_STR
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty
+            )*
+            $(,)?
+        }
+    ) => {
+        compile_error!(
+            "Invalid usage of `wrapper!` macro, cannot implement \
+            `FromStr` trait for wrapper types with multiple fields \
+            but no default values given."
+        );
+    };
+    // ================ Impl `FromStr` trait for the wrapper type. ================
+
+    // ================ Impl `TryFrom` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_TRY_FROM [$validate:path] [$error:ty]
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::TryFrom<$inner_ty> for $name$(<$($lt),+>)? {
+            type Error = $error;
+
+            #[inline(always)]
+            fn try_from(inner: $inner_ty) -> ::core::result::Result<Self, Self::Error> {
+                Self::try_new(inner)
+            }
+        }
+
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
+            /// Creates a new instance of the wrapper type from the inner value,
+            /// returning an error if the validation fails.
+            #[allow(unreachable_pub)]
+            #[inline(always)]
+            pub fn try_new(inner: $inner_ty) -> ::core::result::Result<Self, $error> {
+                $validate(&inner)?;
+
+                // SAFETY: the inner value has been validated.
+                ::core::result::Result::Ok(unsafe { Self::new_unchecked(inner) })
+            }
+
+            /// Creates a new instance of the wrapper type from the inner value,
+            /// without validation.
+            ///
+            /// # Safety
+            ///
+            /// The caller must ensure that the inner value passes the validation.
+            #[allow(unreachable_pub)]
+            #[inline(always)]
+            pub const unsafe fn new_unchecked(inner: $inner_ty) -> Self {
+                Self {
+                    inner,
+                }
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_TRY_FROM [$validate:path] [$error:ty]
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty = $field_default:expr
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::TryFrom<$inner_ty> for $name$(<$($lt),+>)? {
+            type Error = $error;
+
+            #[inline(always)]
+            fn try_from($inner: $inner_ty) -> ::core::result::Result<Self, Self::Error> {
+                Self::try_new($inner)
+            }
+        }
+
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
+            /// Creates a new instance of the wrapper type from the inner value,
+            /// returning an error if the validation fails.
+            #[allow(unreachable_pub)]
+            #[inline(always)]
+            pub fn try_new($inner: $inner_ty) -> ::core::result::Result<Self, $error> {
+                $validate(&$inner)?;
+
+                // SAFETY: the inner value has been validated.
+                ::core::result::Result::Ok(unsafe { Self::new_unchecked($inner) })
+            }
+
+            /// Creates a new instance of the wrapper type from the inner value,
+            /// without validation.
+            ///
+            /// # Safety
+            ///
+            /// The caller must ensure that the inner value passes the validation.
+            #[allow(unreachable_pub)]
+            #[inline(always)]
+            pub const unsafe fn new_unchecked($inner: $inner_ty) -> Self {
+                Self {
+                    $inner,
+                    $(
+                        $field: $field_default,
+                    )*
+                }
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_TRY_FROM [$validate:path]
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::TryFrom<$inner_ty> for $name$(<$($lt),+>)? {
+            type Error = $inner_ty;
+
+            #[inline(always)]
+            fn try_from(inner: $inner_ty) -> ::core::result::Result<Self, Self::Error> {
+                Self::try_new(inner)
+            }
+        }
+
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
+            /// Creates a new instance of the wrapper type from the inner value,
+            /// returning an error if the validation fails.
+            #[allow(unreachable_pub)]
+            #[inline(always)]
+            pub fn try_new(inner: $inner_ty) -> ::core::result::Result<Self, $inner_ty> {
+                if !$validate(&inner) {
+                    return ::core::result::Result::Err(inner);
+                }
+
+                // SAFETY: the inner value has been validated.
+                ::core::result::Result::Ok(unsafe { Self::new_unchecked(inner) })
+            }
+
+            /// Creates a new instance of the wrapper type from the inner value,
+            /// without validation.
+            ///
+            /// # Safety
+            ///
+            /// The caller must ensure that the inner value passes the validation.
+            #[allow(unreachable_pub)]
+            #[inline(always)]
+            pub const unsafe fn new_unchecked(inner: $inner_ty) -> Self {
+                Self {
+                    inner,
+                }
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_TRY_FROM [$validate:path]
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty = $field_default:expr
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::TryFrom<$inner_ty> for $name$(<$($lt),+>)? {
+            type Error = $inner_ty;
+
+            #[inline(always)]
+            fn try_from($inner: $inner_ty) -> ::core::result::Result<Self, Self::Error> {
+                Self::try_new($inner)
+            }
+        }
+
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
+            /// Creates a new instance of the wrapper type from the inner value,
+            /// returning an error if the validation fails.
+            #[allow(unreachable_pub)]
+            #[inline(always)]
+            pub fn try_new($inner: $inner_ty) -> ::core::result::Result<Self, $inner_ty> {
+                if !$validate(&$inner) {
+                    return ::core::result::Result::Err($inner);
+                }
+
+                // SAFETY: the inner value has been validated.
+                ::core::result::Result::Ok(unsafe { Self::new_unchecked($inner) })
+            }
+
+            /// Creates a new instance of the wrapper type from the inner value,
+            /// without validation.
+            ///
+            /// # Safety
+            ///
+            /// The caller must ensure that the inner value passes the validation.
+            #[allow(unreachable_pub)]
+            #[inline(always)]
+            pub const unsafe fn new_unchecked($inner: $inner_ty) -> Self {
+                Self {
+                    $inner,
+                    $(
+                        $field: $field_default,
+                    )*
+                }
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_TRY_FROM [$validate:path] $([$error:ty])?
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty
+            )*
+            $(,)?
+        }
+    ) => {
+        compile_error!(
+            "Invalid usage of `wrapper!` macro, cannot implement \
+            `TryFrom` trait for wrapper types with multiple fields \
+            but no default values given."
+        );
+    };
+    // ================ Impl `TryFrom` trait for the wrapper type. ================
+
+    // ================ Impl `Default` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_DEFAULT
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::default::Default for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::default::Default,
+        {
+            #[inline(always)]
+            fn default() -> Self {
+                Self::const_from(::core::default::Default::default())
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_DEFAULT
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty = $field_default:expr
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::default::Default for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::default::Default,
+        {
+            #[inline(always)]
+            fn default() -> Self {
+                Self::const_from(::core::default::Default::default())
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_DEFAULT
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty
+            )*
+            $(,)?
+        }
+    ) => {
+        compile_error!(
+            "Invalid usage of `wrapper!` macro, cannot implement \
+            `Default` trait for wrapper types with multiple fields \
+            but no default values given."
+        );
+    };
+    // ================ Impl `Default` trait for the wrapper type. ================
+
+    // ================ Impl `IntoIterator` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_INTO_ITERATOR
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::iter::IntoIterator for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::iter::IntoIterator,
+        {
+            type Item = <$inner_ty as ::core::iter::IntoIterator>::Item;
+            type IntoIter = <$inner_ty as ::core::iter::IntoIterator>::IntoIter;
+
+            #[inline(always)]
+            fn into_iter(self) -> Self::IntoIter {
+                ::core::iter::IntoIterator::into_iter(self.inner)
+            }
+        }
+
+        impl<'__lhs $(, $($lt$(:$clt$(+$dlt)*)?),+)?> ::core::iter::IntoIterator for &'__lhs $name$(<$($lt),+>)?
+        where
+            &'__lhs $inner_ty: ::core::iter::IntoIterator,
+        {
+            type Item = <&'__lhs $inner_ty as ::core::iter::IntoIterator>::Item;
+            type IntoIter = <&'__lhs $inner_ty as ::core::iter::IntoIterator>::IntoIter;
+
+            #[inline(always)]
+            fn into_iter(self) -> Self::IntoIter {
+                ::core::iter::IntoIterator::into_iter(&self.inner)
+            }
+        }
+
+        impl<'__lhs $(, $($lt$(:$clt$(+$dlt)*)?),+)?> ::core::iter::IntoIterator for &'__lhs mut $name$(<$($lt),+>)?
+        where
+            &'__lhs mut $inner_ty: ::core::iter::IntoIterator,
+        {
+            type Item = <&'__lhs mut $inner_ty as ::core::iter::IntoIterator>::Item;
+            type IntoIter = <&'__lhs mut $inner_ty as ::core::iter::IntoIterator>::IntoIter;
+
+            #[inline(always)]
+            fn into_iter(self) -> Self::IntoIter {
+                ::core::iter::IntoIterator::into_iter(&mut self.inner)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_INTO_ITERATOR
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::iter::IntoIterator for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::iter::IntoIterator,
+        {
+            type Item = <$inner_ty as ::core::iter::IntoIterator>::Item;
+            type IntoIter = <$inner_ty as ::core::iter::IntoIterator>::IntoIter;
+
+            #[inline(always)]
+            fn into_iter(self) -> Self::IntoIter {
+                ::core::iter::IntoIterator::into_iter(self.$inner)
+            }
+        }
+
+        impl<'__lhs $(, $($lt$(:$clt$(+$dlt)*)?),+)?> ::core::iter::IntoIterator for &'__lhs $name$(<$($lt),+>)?
+        where
+            &'__lhs $inner_ty: ::core::iter::IntoIterator,
+        {
+            type Item = <&'__lhs $inner_ty as ::core::iter::IntoIterator>::Item;
+            type IntoIter = <&'__lhs $inner_ty as ::core::iter::IntoIterator>::IntoIter;
+
+            #[inline(always)]
+            fn into_iter(self) -> Self::IntoIter {
+                ::core::iter::IntoIterator::into_iter(&self.$inner)
+            }
+        }
+
+        impl<'__lhs $(, $($lt$(:$clt$(+$dlt)*)?),+)?> ::core::iter::IntoIterator for &'__lhs mut $name$(<$($lt),+>)?
+        where
+            &'__lhs mut $inner_ty: ::core::iter::IntoIterator,
+        {
+            type Item = <&'__lhs mut $inner_ty as ::core::iter::IntoIterator>::Item;
+            type IntoIter = <&'__lhs mut $inner_ty as ::core::iter::IntoIterator>::IntoIter;
+
+            #[inline(always)]
+            fn into_iter(self) -> Self::IntoIter {
+                ::core::iter::IntoIterator::into_iter(&mut self.$inner)
+            }
+        }
+    };
+    // ================ Impl `IntoIterator` trait for the wrapper type. ================
+
+    // ================ Impl `FromIterator` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_FROM_ITERATOR
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl<$($($lt$(:$clt$(+$dlt)*)?,)+)? __Item> ::core::iter::FromIterator<__Item> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::iter::FromIterator<__Item>,
+        {
+            #[inline(always)]
+            fn from_iter<__I: ::core::iter::IntoIterator<Item = __Item>>(iter: __I) -> Self {
+                Self::const_from(::core::iter::FromIterator::from_iter(iter))
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_FROM_ITERATOR
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty = $field_default:expr
+            )*
+            $(,)?
+        }
+    ) => {
+        impl<$($($lt$(:$clt$(+$dlt)*)?,)+)? __Item> ::core::iter::FromIterator<__Item> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::iter::FromIterator<__Item>,
+        {
+            #[inline(always)]
+            fn from_iter<__I: ::core::iter::IntoIterator<Item = __Item>>(iter: __I) -> Self {
+                Self::const_from(::core::iter::FromIterator::from_iter(iter))
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_FROM_ITERATOR
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty
+            )*
+            $(,)?
+        }
+    ) => {
+        compile_error!(
+            "Invalid usage of `wrapper!` macro, cannot implement \
+            `FromIterator` trait for wrapper types with multiple fields \
+            but no default values given."
+        );
+    };
+    // ================ Impl `FromIterator` trait for the wrapper type. ================
+
+    // ================ Impl `Extend` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_EXTEND
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl<$($($lt$(:$clt$(+$dlt)*)?,)+)? __Item> ::core::iter::Extend<__Item> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::iter::Extend<__Item>,
+        {
+            #[inline(always)]
+            fn extend<__I: ::core::iter::IntoIterator<Item = __Item>>(&mut self, iter: __I) {
+                ::core::iter::Extend::extend(&mut self.inner, iter)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_EXTEND
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl<$($($lt$(:$clt$(+$dlt)*)?,)+)? __Item> ::core::iter::Extend<__Item> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::iter::Extend<__Item>,
+        {
+            #[inline(always)]
+            fn extend<__I: ::core::iter::IntoIterator<Item = __Item>>(&mut self, iter: __I) {
+                ::core::iter::Extend::extend(&mut self.$inner, iter)
+            }
+        }
+    };
+    // ================ Impl `Extend` trait for the wrapper type. ================
+
+    // ================ Impl `Index` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_INDEX [$($idx_lt:lifetime)?] <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl<$($idx_lt,)? $($($lt$(:$clt$(+$dlt)*)?),+)?> ::core::ops::Index<$target> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::ops::Index<$target>,
+        {
+            type Output = <$inner_ty as ::core::ops::Index<$target>>::Output;
+
+            #[inline(always)]
+            fn index(&self, index: $target) -> &Self::Output {
+                ::core::ops::Index::index(&self.inner, index)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_INDEX [$($idx_lt:lifetime)?] <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl<$($idx_lt,)? $($($lt$(:$clt$(+$dlt)*)?),+)?> ::core::ops::Index<$target> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::ops::Index<$target>,
+        {
+            type Output = <$inner_ty as ::core::ops::Index<$target>>::Output;
+
+            #[inline(always)]
+            fn index(&self, index: $target) -> &Self::Output {
+                ::core::ops::Index::index(&self.$inner, index)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_INDEX
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl<$($($lt$(:$clt$(+$dlt)*)?,)+)? __Idx> ::core::ops::Index<__Idx> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::ops::Index<__Idx>,
+        {
+            type Output = <$inner_ty as ::core::ops::Index<__Idx>>::Output;
+
+            #[inline(always)]
+            fn index(&self, index: __Idx) -> &Self::Output {
+                ::core::ops::Index::index(&self.inner, index)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_INDEX
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl<$($($lt$(:$clt$(+$dlt)*)?,)+)? __Idx> ::core::ops::Index<__Idx> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::ops::Index<__Idx>,
+        {
+            type Output = <$inner_ty as ::core::ops::Index<__Idx>>::Output;
+
+            #[inline(always)]
+            fn index(&self, index: __Idx) -> &Self::Output {
+                ::core::ops::Index::index(&self.$inner, index)
+            }
+        }
+    };
+    // ================ Impl `Index` trait for the wrapper type. ================
+
+    // ================ Impl `IndexMut` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_INDEX_MUT [$($idx_lt:lifetime)?] <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl<$($idx_lt,)? $($($lt$(:$clt$(+$dlt)*)?),+)?> ::core::ops::IndexMut<$target> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::ops::IndexMut<$target>,
+        {
+            #[inline(always)]
+            fn index_mut(&mut self, index: $target) -> &mut Self::Output {
+                ::core::ops::IndexMut::index_mut(&mut self.inner, index)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_INDEX_MUT [$($idx_lt:lifetime)?] <$target:ty>
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl<$($idx_lt,)? $($($lt$(:$clt$(+$dlt)*)?),+)?> ::core::ops::IndexMut<$target> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::ops::IndexMut<$target>,
+        {
+            #[inline(always)]
+            fn index_mut(&mut self, index: $target) -> &mut Self::Output {
+                ::core::ops::IndexMut::index_mut(&mut self.$inner, index)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_INDEX_MUT
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl<$($($lt$(:$clt$(+$dlt)*)?,)+)? __Idx> ::core::ops::IndexMut<__Idx> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::ops::IndexMut<__Idx>,
+        {
+            #[inline(always)]
+            fn index_mut(&mut self, index: __Idx) -> &mut Self::Output {
+                ::core::ops::IndexMut::index_mut(&mut self.inner, index)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_INDEX_MUT
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl<$($($lt$(:$clt$(+$dlt)*)?,)+)? __Idx> ::core::ops::IndexMut<__Idx> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::ops::IndexMut<__Idx>,
+        {
+            #[inline(always)]
+            fn index_mut(&mut self, index: __Idx) -> &mut Self::Output {
+                ::core::ops::IndexMut::index_mut(&mut self.$inner, index)
+            }
+        }
+    };
+    // ================ Impl `IndexMut` trait for the wrapper type. ================
+
+    // ================ Impl `Sum` and `Product` traits for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_ITER_FOLD $trait:ident $method:ident
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_ITER_FOLD $trait $method
+            $vis struct $name$(<$($lt$(:$clt$(+$dlt)*)?),+>)? {
+                $inner_vis inner: $inner_ty
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_ITER_FOLD $trait:ident $method:ident
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty = $field_default:expr
+            )*
+            $(,)?
+        }
+    ) => {
+        // `Iterator<Item = Wrapper>`
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::iter::$trait for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::iter::$trait,
+        {
+            #[inline(always)]
+            fn $method<__I: ::core::iter::Iterator<Item = Self>>(iter: __I) -> Self {
+                Self::const_from(::core::iter::$trait::$method(iter.map(|this| this.$inner)))
+            }
+        }
+
+        // `Iterator<Item = &Wrapper>`
+        impl<'__lhs $(, $($lt$(:$clt$(+$dlt)*)?),+)?> ::core::iter::$trait<&'__lhs $name$(<$($lt),+>)?> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::iter::$trait<&'__lhs $inner_ty>,
+        {
+            #[inline(always)]
+            fn $method<__I: ::core::iter::Iterator<Item = &'__lhs Self>>(iter: __I) -> Self {
+                Self::const_from(::core::iter::$trait::$method(iter.map(|this| &this.$inner)))
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_ITER_FOLD $trait:ident $method:ident
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty
+            )*
+            $(,)?
+        }
+    ) => {
+        compile_error!(
+            "Invalid usage of `wrapper!` macro, cannot implement \
+            `Sum` or `Product` trait for wrapper types with multiple fields \
+            but no default values given."
+        );
+    };
+    // ================ Impl `Sum` and `Product` traits for the wrapper type. ================
+
+    // ================ Impl `Iterator` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_ITERATOR
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::iter::Iterator for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::iter::Iterator,
+        {
+            type Item = <$inner_ty as ::core::iter::Iterator>::Item;
+
+            #[inline(always)]
+            fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                ::core::iter::Iterator::next(&mut self.inner)
+            }
+
+            #[inline(always)]
+            fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {
+                ::core::iter::Iterator::size_hint(&self.inner)
+            }
+
+            #[inline(always)]
+            fn count(self) -> usize {
+                ::core::iter::Iterator::count(self.inner)
+            }
+
+            #[inline(always)]
+            fn last(self) -> ::core::option::Option<Self::Item> {
+                ::core::iter::Iterator::last(self.inner)
+            }
+
+            #[inline(always)]
+            fn nth(&mut self, n: usize) -> ::core::option::Option<Self::Item> {
+                ::core::iter::Iterator::nth(&mut self.inner, n)
+            }
+
+            #[inline(always)]
+            fn for_each<__F>(self, f: __F)
+            where
+                __F: ::core::ops::FnMut(Self::Item),
+            {
+                ::core::iter::Iterator::for_each(self.inner, f)
+            }
+
+            #[inline(always)]
+            fn fold<__B, __F>(self, init: __B, f: __F) -> __B
+            where
+                __F: ::core::ops::FnMut(__B, Self::Item) -> __B,
+            {
+                ::core::iter::Iterator::fold(self.inner, init, f)
+            }
+
+            #[inline(always)]
+            fn all<__F>(&mut self, f: __F) -> bool
+            where
+                __F: ::core::ops::FnMut(Self::Item) -> bool,
+            {
+                ::core::iter::Iterator::all(&mut self.inner, f)
+            }
+
+            #[inline(always)]
+            fn any<__F>(&mut self, f: __F) -> bool
+            where
+                __F: ::core::ops::FnMut(Self::Item) -> bool,
+            {
+                ::core::iter::Iterator::any(&mut self.inner, f)
+            }
+
+            #[inline(always)]
+            fn find<__P>(&mut self, predicate: __P) -> ::core::option::Option<Self::Item>
+            where
+                __P: ::core::ops::FnMut(&Self::Item) -> bool,
+            {
+                ::core::iter::Iterator::find(&mut self.inner, predicate)
+            }
+
+            #[inline(always)]
+            fn find_map<__B, __F>(&mut self, f: __F) -> ::core::option::Option<__B>
+            where
+                __F: ::core::ops::FnMut(Self::Item) -> ::core::option::Option<__B>,
+            {
+                ::core::iter::Iterator::find_map(&mut self.inner, f)
+            }
+
+            #[inline(always)]
+            fn position<__P>(&mut self, predicate: __P) -> ::core::option::Option<usize>
+            where
+                __P: ::core::ops::FnMut(Self::Item) -> bool,
+            {
+                ::core::iter::Iterator::position(&mut self.inner, predicate)
+            }
+
+            #[inline(always)]
+            fn max(self) -> ::core::option::Option<Self::Item>
+            where
+                Self::Item: ::core::cmp::Ord,
+            {
+                ::core::iter::Iterator::max(self.inner)
+            }
+
+            #[inline(always)]
+            fn min(self) -> ::core::option::Option<Self::Item>
+            where
+                Self::Item: ::core::cmp::Ord,
+            {
+                ::core::iter::Iterator::min(self.inner)
+            }
+
+            #[inline(always)]
+            fn collect<__B>(self) -> __B
+            where
+                __B: ::core::iter::FromIterator<Self::Item>,
+            {
+                ::core::iter::Iterator::collect(self.inner)
+            }
+
+            #[inline(always)]
+            fn partition<__B, __F>(self, f: __F) -> (__B, __B)
+            where
+                __B: ::core::default::Default + ::core::iter::Extend<Self::Item>,
+                __F: ::core::ops::FnMut(&Self::Item) -> bool,
+            {
+                ::core::iter::Iterator::partition(self.inner, f)
+            }
+
+            #[inline(always)]
+            fn reduce<__F>(self, f: __F) -> ::core::option::Option<Self::Item>
+            where
+                __F: ::core::ops::FnMut(Self::Item, Self::Item) -> Self::Item,
+            {
+                ::core::iter::Iterator::reduce(self.inner, f)
+            }
+
+            #[inline(always)]
+            fn max_by_key<__B, __F>(self, f: __F) -> ::core::option::Option<Self::Item>
+            where
+                __B: ::core::cmp::Ord,
+                __F: ::core::ops::FnMut(&Self::Item) -> __B,
+            {
+                ::core::iter::Iterator::max_by_key(self.inner, f)
+            }
+
+            #[inline(always)]
+            fn max_by<__F>(self, compare: __F) -> ::core::option::Option<Self::Item>
+            where
+                __F: ::core::ops::FnMut(&Self::Item, &Self::Item) -> ::core::cmp::Ordering,
+            {
+                ::core::iter::Iterator::max_by(self.inner, compare)
+            }
+
+            #[inline(always)]
+            fn min_by_key<__B, __F>(self, f: __F) -> ::core::option::Option<Self::Item>
+            where
+                __B: ::core::cmp::Ord,
+                __F: ::core::ops::FnMut(&Self::Item) -> __B,
+            {
+                ::core::iter::Iterator::min_by_key(self.inner, f)
+            }
+
+            #[inline(always)]
+            fn min_by<__F>(self, compare: __F) -> ::core::option::Option<Self::Item>
+            where
+                __F: ::core::ops::FnMut(&Self::Item, &Self::Item) -> ::core::cmp::Ordering,
+            {
+                ::core::iter::Iterator::min_by(self.inner, compare)
+            }
+
+
+            #[inline(always)]
+            fn sum<__S>(self) -> __S
+            where
+                __S: ::core::iter::Sum<Self::Item>,
+            {
+                ::core::iter::Iterator::sum(self.inner)
+            }
+
+            #[inline(always)]
+            fn product<__P>(self) -> __P
+            where
+                __P: ::core::iter::Product<Self::Item>,
+            {
+                ::core::iter::Iterator::product(self.inner)
+            }
+
+            #[inline(always)]
+            fn cmp<__I>(self, other: __I) -> ::core::cmp::Ordering
+            where
+                __I: ::core::iter::IntoIterator<Item = Self::Item>,
+                Self::Item: ::core::cmp::Ord,
+            {
+                ::core::iter::Iterator::cmp(self.inner, other)
+            }
+
+            #[inline(always)]
+            fn partial_cmp<__I>(self, other: __I) -> ::core::option::Option<::core::cmp::Ordering>
+            where
+                __I: ::core::iter::IntoIterator,
+                Self::Item: ::core::cmp::PartialOrd<__I::Item>,
+            {
+                ::core::iter::Iterator::partial_cmp(self.inner, other)
+            }
+
+            #[inline(always)]
+            fn eq<__I>(self, other: __I) -> bool
+            where
+                __I: ::core::iter::IntoIterator,
+                Self::Item: ::core::cmp::PartialEq<__I::Item>,
+            {
+                ::core::iter::Iterator::eq(self.inner, other)
+            }
+
+            #[inline(always)]
+            fn ne<__I>(self, other: __I) -> bool
+            where
+                __I: ::core::iter::IntoIterator,
+                Self::Item: ::core::cmp::PartialEq<__I::Item>,
+            {
+                ::core::iter::Iterator::ne(self.inner, other)
+            }
+
+            #[inline(always)]
+            fn lt<__I>(self, other: __I) -> bool
+            where
+                __I: ::core::iter::IntoIterator,
+                Self::Item: ::core::cmp::PartialOrd<__I::Item>,
+            {
+                ::core::iter::Iterator::lt(self.inner, other)
+            }
+
+            #[inline(always)]
+            fn le<__I>(self, other: __I) -> bool
+            where
+                __I: ::core::iter::IntoIterator,
+                Self::Item: ::core::cmp::PartialOrd<__I::Item>,
+            {
+                ::core::iter::Iterator::le(self.inner, other)
+            }
+
+            #[inline(always)]
+            fn gt<__I>(self, other: __I) -> bool
+            where
+                __I: ::core::iter::IntoIterator,
+                Self::Item: ::core::cmp::PartialOrd<__I::Item>,
+            {
+                ::core::iter::Iterator::gt(self.inner, other)
+            }
+
+            #[inline(always)]
+            fn ge<__I>(self, other: __I) -> bool
+            where
+                __I: ::core::iter::IntoIterator,
+                Self::Item: ::core::cmp::PartialOrd<__I::Item>,
+            {
+                ::core::iter::Iterator::ge(self.inner, other)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_ITERATOR
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::iter::Iterator for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::iter::Iterator,
+        {
+            type Item = <$inner_ty as ::core::iter::Iterator>::Item;
+
+            #[inline(always)]
+            fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                ::core::iter::Iterator::next(&mut self.$inner)
+            }
+
+            #[inline(always)]
+            fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {
+                ::core::iter::Iterator::size_hint(&self.$inner)
+            }
+
+            #[inline(always)]
+            fn count(self) -> usize {
+                ::core::iter::Iterator::count(self.$inner)
+            }
+
+            #[inline(always)]
+            fn last(self) -> ::core::option::Option<Self::Item> {
+                ::core::iter::Iterator::last(self.$inner)
+            }
+
+            #[inline(always)]
+            fn nth(&mut self, n: usize) -> ::core::option::Option<Self::Item> {
+                ::core::iter::Iterator::nth(&mut self.$inner, n)
+            }
+
+            #[inline(always)]
+            fn for_each<__F>(self, f: __F)
+            where
+                __F: ::core::ops::FnMut(Self::Item),
+            {
+                ::core::iter::Iterator::for_each(self.$inner, f)
+            }
+
+            #[inline(always)]
+            fn fold<__B, __F>(self, init: __B, f: __F) -> __B
+            where
+                __F: ::core::ops::FnMut(__B, Self::Item) -> __B,
+            {
+                ::core::iter::Iterator::fold(self.$inner, init, f)
+            }
+
+            #[inline(always)]
+            fn all<__F>(&mut self, f: __F) -> bool
+            where
+                __F: ::core::ops::FnMut(Self::Item) -> bool,
+            {
+                ::core::iter::Iterator::all(&mut self.$inner, f)
+            }
+
+            #[inline(always)]
+            fn any<__F>(&mut self, f: __F) -> bool
+            where
+                __F: ::core::ops::FnMut(Self::Item) -> bool,
+            {
+                ::core::iter::Iterator::any(&mut self.$inner, f)
+            }
+
+            #[inline(always)]
+            fn find<__P>(&mut self, predicate: __P) -> ::core::option::Option<Self::Item>
+            where
+                __P: ::core::ops::FnMut(&Self::Item) -> bool,
+            {
+                ::core::iter::Iterator::find(&mut self.$inner, predicate)
+            }
+
+            #[inline(always)]
+            fn find_map<__B, __F>(&mut self, f: __F) -> ::core::option::Option<__B>
+            where
+                __F: ::core::ops::FnMut(Self::Item) -> ::core::option::Option<__B>,
+            {
+                ::core::iter::Iterator::find_map(&mut self.$inner, f)
+            }
+
+            #[inline(always)]
+            fn position<__P>(&mut self, predicate: __P) -> ::core::option::Option<usize>
+            where
+                __P: ::core::ops::FnMut(Self::Item) -> bool,
+            {
+                ::core::iter::Iterator::position(&mut self.$inner, predicate)
+            }
+
+            #[inline(always)]
+            fn max(self) -> ::core::option::Option<Self::Item>
+            where
+                Self::Item: ::core::cmp::Ord,
+            {
+                ::core::iter::Iterator::max(self.$inner)
+            }
+
+            #[inline(always)]
+            fn min(self) -> ::core::option::Option<Self::Item>
+            where
+                Self::Item: ::core::cmp::Ord,
+            {
+                ::core::iter::Iterator::min(self.$inner)
+            }
+
+            #[inline(always)]
+            fn collect<__B>(self) -> __B
+            where
+                __B: ::core::iter::FromIterator<Self::Item>,
+            {
+                ::core::iter::Iterator::collect(self.$inner)
+            }
+
+            #[inline(always)]
+            fn partition<__B, __F>(self, f: __F) -> (__B, __B)
+            where
+                __B: ::core::default::Default + ::core::iter::Extend<Self::Item>,
+                __F: ::core::ops::FnMut(&Self::Item) -> bool,
+            {
+                ::core::iter::Iterator::partition(self.$inner, f)
+            }
+
+            #[inline(always)]
+            fn reduce<__F>(self, f: __F) -> ::core::option::Option<Self::Item>
+            where
+                __F: ::core::ops::FnMut(Self::Item, Self::Item) -> Self::Item,
+            {
+                ::core::iter::Iterator::reduce(self.$inner, f)
+            }
+
+            #[inline(always)]
+            fn max_by_key<__B, __F>(self, f: __F) -> ::core::option::Option<Self::Item>
+            where
+                __B: ::core::cmp::Ord,
+                __F: ::core::ops::FnMut(&Self::Item) -> __B,
+            {
+                ::core::iter::Iterator::max_by_key(self.$inner, f)
+            }
+
+            #[inline(always)]
+            fn max_by<__F>(self, compare: __F) -> ::core::option::Option<Self::Item>
+            where
+                __F: ::core::ops::FnMut(&Self::Item, &Self::Item) -> ::core::cmp::Ordering,
+            {
+                ::core::iter::Iterator::max_by(self.$inner, compare)
+            }
+
+            #[inline(always)]
+            fn min_by_key<__B, __F>(self, f: __F) -> ::core::option::Option<Self::Item>
+            where
+                __B: ::core::cmp::Ord,
+                __F: ::core::ops::FnMut(&Self::Item) -> __B,
+            {
+                ::core::iter::Iterator::min_by_key(self.$inner, f)
+            }
+
+            #[inline(always)]
+            fn min_by<__F>(self, compare: __F) -> ::core::option::Option<Self::Item>
+            where
+                __F: ::core::ops::FnMut(&Self::Item, &Self::Item) -> ::core::cmp::Ordering,
+            {
+                ::core::iter::Iterator::min_by(self.$inner, compare)
+            }
+
+
+            #[inline(always)]
+            fn sum<__S>(self) -> __S
+            where
+                __S: ::core::iter::Sum<Self::Item>,
+            {
+                ::core::iter::Iterator::sum(self.$inner)
+            }
+
+            #[inline(always)]
+            fn product<__P>(self) -> __P
+            where
+                __P: ::core::iter::Product<Self::Item>,
+            {
+                ::core::iter::Iterator::product(self.$inner)
+            }
+
+            #[inline(always)]
+            fn cmp<__I>(self, other: __I) -> ::core::cmp::Ordering
+            where
+                __I: ::core::iter::IntoIterator<Item = Self::Item>,
+                Self::Item: ::core::cmp::Ord,
+            {
+                ::core::iter::Iterator::cmp(self.$inner, other)
+            }
+
+            #[inline(always)]
+            fn partial_cmp<__I>(self, other: __I) -> ::core::option::Option<::core::cmp::Ordering>
+            where
+                __I: ::core::iter::IntoIterator,
+                Self::Item: ::core::cmp::PartialOrd<__I::Item>,
+            {
+                ::core::iter::Iterator::partial_cmp(self.$inner, other)
+            }
+
+            #[inline(always)]
+            fn eq<__I>(self, other: __I) -> bool
+            where
+                __I: ::core::iter::IntoIterator,
+                Self::Item: ::core::cmp::PartialEq<__I::Item>,
+            {
+                ::core::iter::Iterator::eq(self.$inner, other)
+            }
+
+            #[inline(always)]
+            fn ne<__I>(self, other: __I) -> bool
+            where
+                __I: ::core::iter::IntoIterator,
+                Self::Item: ::core::cmp::PartialEq<__I::Item>,
+            {
+                ::core::iter::Iterator::ne(self.$inner, other)
+            }
+
+            #[inline(always)]
+            fn lt<__I>(self, other: __I) -> bool
+            where
+                __I: ::core::iter::IntoIterator,
+                Self::Item: ::core::cmp::PartialOrd<__I::Item>,
+            {
+                ::core::iter::Iterator::lt(self.$inner, other)
+            }
+
+            #[inline(always)]
+            fn le<__I>(self, other: __I) -> bool
+            where
+                __I: ::core::iter::IntoIterator,
+                Self::Item: ::core::cmp::PartialOrd<__I::Item>,
+            {
+                ::core::iter::Iterator::le(self.$inner, other)
+            }
+
+            #[inline(always)]
+            fn gt<__I>(self, other: __I) -> bool
+            where
+                __I: ::core::iter::IntoIterator,
+                Self::Item: ::core::cmp::PartialOrd<__I::Item>,
+            {
+                ::core::iter::Iterator::gt(self.$inner, other)
+            }
+
+            #[inline(always)]
+            fn ge<__I>(self, other: __I) -> bool
+            where
+                __I: ::core::iter::IntoIterator,
+                Self::Item: ::core::cmp::PartialOrd<__I::Item>,
+            {
+                ::core::iter::Iterator::ge(self.$inner, other)
+            }
+        }
+    };
+    // ================ Impl `Iterator` trait for the wrapper type. ================
+
+    // ================ Impl `DoubleEndedIterator` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_DOUBLE_ENDED_ITERATOR
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::iter::DoubleEndedIterator for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::iter::DoubleEndedIterator,
+        {
+            #[inline(always)]
+            fn next_back(&mut self) -> ::core::option::Option<Self::Item> {
+                ::core::iter::DoubleEndedIterator::next_back(&mut self.inner)
+            }
+
+            #[inline(always)]
+            fn nth_back(&mut self, n: usize) -> ::core::option::Option<Self::Item> {
+                ::core::iter::DoubleEndedIterator::nth_back(&mut self.inner, n)
+            }
+
+            #[inline(always)]
+            fn rfold<__B, __F>(self, init: __B, f: __F) -> __B
+            where
+                __F: ::core::ops::FnMut(__B, Self::Item) -> __B,
+            {
+                ::core::iter::DoubleEndedIterator::rfold(self.inner, init, f)
+            }
+
+            #[inline(always)]
+            fn rfind<__P>(&mut self, predicate: __P) -> ::core::option::Option<Self::Item>
+            where
+                __P: ::core::ops::FnMut(&Self::Item) -> bool,
+            {
+                ::core::iter::DoubleEndedIterator::rfind(&mut self.inner, predicate)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_DOUBLE_ENDED_ITERATOR
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
         }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::iter::DoubleEndedIterator for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::iter::DoubleEndedIterator,
+        {
+            #[inline(always)]
+            fn next_back(&mut self) -> ::core::option::Option<Self::Item> {
+                ::core::iter::DoubleEndedIterator::next_back(&mut self.$inner)
+            }
 
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
             #[inline(always)]
-            #[doc = concat!("Creates a new instance of [`", stringify!($name), "`]")]
-            $inner_vis const fn const_from(inner: $inner_ty) -> Self {
-                Self {
-                    inner,
-                }
+            fn nth_back(&mut self, n: usize) -> ::core::option::Option<Self::Item> {
+                ::core::iter::DoubleEndedIterator::nth_back(&mut self.$inner, n)
+            }
+
+            #[inline(always)]
+            fn rfold<__B, __F>(self, init: __B, f: __F) -> __B
+            where
+                __F: ::core::ops::FnMut(__B, Self::Item) -> __B,
+            {
+                ::core::iter::DoubleEndedIterator::rfold(self.$inner, init, f)
+            }
+
+            #[inline(always)]
+            fn rfind<__P>(&mut self, predicate: __P) -> ::core::option::Option<Self::Item>
+            where
+                __P: ::core::ops::FnMut(&Self::Item) -> bool,
+            {
+                ::core::iter::DoubleEndedIterator::rfind(&mut self.$inner, predicate)
             }
         }
     };
+    // ================ Impl `DoubleEndedIterator` trait for the wrapper type. ================
 
+    // ================ Impl `ExactSizeIterator` trait for the wrapper type. ================
     (
-        @INTERNAL IMPL
-        $(#[$outer:meta])*
+        @INTERNAL WRAPPER_IMPL_EXACT_SIZE_ITERATOR
+        $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
-        $(#[$outer])*
-        #[repr(transparent)]
-        $vis struct $name$(<$($lt),+>)? {
-            /// Inner value
-            $inner_vis inner: $inner_ty,
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::iter::ExactSizeIterator for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::iter::ExactSizeIterator,
+        {
+            #[inline(always)]
+            fn len(&self) -> usize {
+                ::core::iter::ExactSizeIterator::len(&self.inner)
+            }
         }
-
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_EXACT_SIZE_ITERATOR
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::iter::ExactSizeIterator for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::iter::ExactSizeIterator,
+        {
             #[inline(always)]
-            #[doc = concat!("Creates a new instance of [`", stringify!($name), "`]")]
-            $inner_vis const fn const_from(inner: $inner_ty) -> Self {
-                Self {
-                    inner,
-                }
+            fn len(&self) -> usize {
+                ::core::iter::ExactSizeIterator::len(&self.$inner)
             }
         }
     };
+    // ================ Impl `ExactSizeIterator` trait for the wrapper type. ================
 
-    // The actual implementation of the wrapper type: `pub struct Name<...> { ... }`
-    // with field initial value provided, make `const_from` const.
+    // ================ Impl `FusedIterator` trait for the wrapper type. ================
     (
-        @INTERNAL IMPL
-        #[repr(align(cache))]
-        $(#[$outer:meta])*
+        @INTERNAL WRAPPER_IMPL_FUSED_ITERATOR
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::iter::FusedIterator for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::iter::FusedIterator,
+        {
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_FUSED_ITERATOR
+        $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
             $(#[$field_inner_meta:meta])*
             $inner_vis:vis $inner:ident: $inner_ty:ty
             $(
                 ,
                 $(#[$field_meta:meta])*
-                $field_vis:vis $field:ident: $field_ty:ty = $field_default: expr
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
             )*
             $(,)?
         }
     ) => {
-        // Starting from Intel's Sandy Bridge, spatial prefetcher is now pulling pairs of 64-byte cache
-        // lines at a time, so we have to align to 128 bytes rather than 64.
-        //
-        // Sources:
-        // - https://www.intel.com/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-optimization-manual.pdf
-        // - https://github.com/facebook/folly/blob/1b5288e6eea6df074758f877c849b6e73bbb9fbb/folly/lang/Align.h#L107
-        //
-        // aarch64/arm64ec's big.LITTLE architecture has asymmetric cores and "big" cores have 128-byte cache line size.
-        //
-        // Sources:
-        // - https://www.mono-project.com/news/2016/09/12/arm64-icache/
-        //
-        // powerpc64 has 128-byte cache line size.
-        //
-        // Sources:
-        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_ppc64x.go#L9
-        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/powerpc/include/asm/cache.h#L26
-        #[cfg_attr(
-            any(
-                target_arch = "x86_64",
-                target_arch = "aarch64",
-                target_arch = "arm64ec",
-                target_arch = "powerpc64",
-            ),
-            repr(align(128))
-        )]
-        // arm, mips, mips64, sparc, and hexagon have 32-byte cache line size.
-        //
-        // Sources:
-        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_arm.go#L7
-        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_mips.go#L7
-        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_mipsle.go#L7
-        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_mips64x.go#L9
-        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/sparc/include/asm/cache.h#L17
-        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/hexagon/include/asm/cache.h#L12
-        #[cfg_attr(
-            any(
-                target_arch = "arm",
-                target_arch = "mips",
-                target_arch = "mips32r6",
-                target_arch = "mips64",
-                target_arch = "mips64r6",
-                target_arch = "sparc",
-                target_arch = "hexagon",
-            ),
-            repr(align(32))
-        )]
-        // m68k has 16-byte cache line size.
-        //
-        // Sources:
-        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/m68k/include/asm/cache.h#L9
-        #[cfg_attr(target_arch = "m68k", repr(align(16)))]
-        // s390x has 256-byte cache line size.
-        //
-        // Sources:
-        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_s390x.go#L7
-        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/s390/include/asm/cache.h#L13
-        #[cfg_attr(target_arch = "s390x", repr(align(256)))]
-        // x86, wasm, riscv, and sparc64 have 64-byte cache line size.
-        //
-        // Sources:
-        // - https://github.com/golang/go/blob/dda2991c2ea0c5914714469c4defc2562a907230/src/internal/cpu/cpu_x86.go#L9
-        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_wasm.go#L7
-        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/riscv/include/asm/cache.h#L10
-        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/sparc/include/asm/cache.h#L19
-        //
-        // All others are assumed to have 64-byte cache line size.
-        #[cfg_attr(
-            not(any(
-                target_arch = "x86_64",
-                target_arch = "aarch64",
-                target_arch = "arm64ec",
-                target_arch = "powerpc64",
-                target_arch = "arm",
-                target_arch = "mips",
-                target_arch = "mips32r6",
-                target_arch = "mips64",
-                target_arch = "mips64r6",
-                target_arch = "sparc",
-                target_arch = "hexagon",
-                target_arch = "m68k",
-                target_arch = "s390x",
-            )),
-            repr(align(64))
-        )]
-        $(#[$outer])*
-        $vis struct $name$(<$($lt),+>)? {
-            $(#[$field_inner_meta])*
-            $inner_vis $inner: $inner_ty,
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::iter::FusedIterator for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::iter::FusedIterator,
+        {
+        }
+    };
+    // ================ Impl `FusedIterator` trait for the wrapper type. ================
+
+    // ================ Impl `Error` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_ERROR
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        $crate::__cfg_std! {
+            Error;
+
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__std::error::Error for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__std::error::Error,
+            {
+                #[inline(always)]
+                fn source(&self) -> ::core::option::Option<&(dyn $crate::__std::error::Error + 'static)> {
+                    $crate::__std::error::Error::source(&self.inner)
+                }
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_ERROR
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
             $(
-                $(#[$field_meta])*
-                $field_vis $field: $field_ty
-            ),*
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        $crate::__cfg_std! {
+            Error;
+
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__std::error::Error for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__std::error::Error,
+            {
+                #[inline(always)]
+                fn source(&self) -> ::core::option::Option<&(dyn $crate::__std::error::Error + 'static)> {
+                    $crate::__std::error::Error::source(&self.$inner)
+                }
+            }
+        }
+    };
+    // ================ Impl `Error` trait for the wrapper type. ================
+
+    // ================ Impl `io::Read` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_IO_READ
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        $crate::__cfg_std! {
+            Read;
+
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__std::io::Read for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__std::io::Read,
+            {
+                #[inline(always)]
+                fn read(&mut self, buf: &mut [u8]) -> $crate::__std::io::Result<usize> {
+                    $crate::__std::io::Read::read(&mut self.inner, buf)
+                }
+
+                #[inline(always)]
+                fn read_vectored(&mut self, bufs: &mut [$crate::__std::io::IoSliceMut<'_>]) -> $crate::__std::io::Result<usize> {
+                    $crate::__std::io::Read::read_vectored(&mut self.inner, bufs)
+                }
+
+                #[inline(always)]
+                fn read_to_end(&mut self, buf: &mut $crate::__std::vec::Vec<u8>) -> $crate::__std::io::Result<usize> {
+                    $crate::__std::io::Read::read_to_end(&mut self.inner, buf)
+                }
+
+                #[inline(always)]
+                fn read_to_string(&mut self, buf: &mut $crate::__std::string::String) -> $crate::__std::io::Result<usize> {
+                    $crate::__std::io::Read::read_to_string(&mut self.inner, buf)
+                }
+
+                #[inline(always)]
+                fn read_exact(&mut self, buf: &mut [u8]) -> $crate::__std::io::Result<()> {
+                    $crate::__std::io::Read::read_exact(&mut self.inner, buf)
+                }
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_IO_READ
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
         }
+    ) => {
+        $crate::__cfg_std! {
+            Read;
+
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__std::io::Read for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__std::io::Read,
+            {
+                #[inline(always)]
+                fn read(&mut self, buf: &mut [u8]) -> $crate::__std::io::Result<usize> {
+                    $crate::__std::io::Read::read(&mut self.$inner, buf)
+                }
+
+                #[inline(always)]
+                fn read_vectored(&mut self, bufs: &mut [$crate::__std::io::IoSliceMut<'_>]) -> $crate::__std::io::Result<usize> {
+                    $crate::__std::io::Read::read_vectored(&mut self.$inner, bufs)
+                }
 
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
-            #[inline(always)]
-            #[doc = concat!("Creates a new instance of [`", stringify!($name), "`]")]
-            $inner_vis const fn const_from($inner: $inner_ty) -> Self {
-                Self {
-                    $inner,
-                    $(
-                        $field: $field_default,
-                    )*
+                #[inline(always)]
+                fn read_to_end(&mut self, buf: &mut $crate::__std::vec::Vec<u8>) -> $crate::__std::io::Result<usize> {
+                    $crate::__std::io::Read::read_to_end(&mut self.$inner, buf)
+                }
+
+                #[inline(always)]
+                fn read_to_string(&mut self, buf: &mut $crate::__std::string::String) -> $crate::__std::io::Result<usize> {
+                    $crate::__std::io::Read::read_to_string(&mut self.$inner, buf)
+                }
+
+                #[inline(always)]
+                fn read_exact(&mut self, buf: &mut [u8]) -> $crate::__std::io::Result<()> {
+                    $crate::__std::io::Read::read_exact(&mut self.$inner, buf)
                 }
             }
         }
     };
+    // ================ Impl `io::Read` trait for the wrapper type. ================
 
+    // ================ Impl `io::Write` trait for the wrapper type. ================
     (
-        @INTERNAL IMPL
-        $(#[$outer:meta])*
+        @INTERNAL WRAPPER_IMPL_IO_WRITE
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        $crate::__cfg_std! {
+            Write;
+
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__std::io::Write for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__std::io::Write,
+            {
+                #[inline(always)]
+                fn write(&mut self, buf: &[u8]) -> $crate::__std::io::Result<usize> {
+                    $crate::__std::io::Write::write(&mut self.inner, buf)
+                }
+
+                #[inline(always)]
+                fn write_vectored(&mut self, bufs: &[$crate::__std::io::IoSlice<'_>]) -> $crate::__std::io::Result<usize> {
+                    $crate::__std::io::Write::write_vectored(&mut self.inner, bufs)
+                }
+
+                #[inline(always)]
+                fn flush(&mut self) -> $crate::__std::io::Result<()> {
+                    $crate::__std::io::Write::flush(&mut self.inner)
+                }
+
+                #[inline(always)]
+                fn write_all(&mut self, buf: &[u8]) -> $crate::__std::io::Result<()> {
+                    $crate::__std::io::Write::write_all(&mut self.inner, buf)
+                }
+
+                #[inline(always)]
+                fn write_fmt(&mut self, fmt: ::core::fmt::Arguments<'_>) -> $crate::__std::io::Result<()> {
+                    $crate::__std::io::Write::write_fmt(&mut self.inner, fmt)
+                }
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_IO_WRITE
+        $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
             $(#[$field_inner_meta:meta])*
             $inner_vis:vis $inner:ident: $inner_ty:ty
             $(
                 ,
                 $(#[$field_meta:meta])*
-                $field_vis:vis $field:ident: $field_ty:ty = $field_default: expr
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
             )*
             $(,)?
         }
     ) => {
-        $(#[$outer])*
-        $vis struct $name$(<$($lt),+>)? {
-            $(#[$field_inner_meta])*
-            $inner_vis $inner: $inner_ty,
+        $crate::__cfg_std! {
+            Write;
+
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__std::io::Write for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__std::io::Write,
+            {
+                #[inline(always)]
+                fn write(&mut self, buf: &[u8]) -> $crate::__std::io::Result<usize> {
+                    $crate::__std::io::Write::write(&mut self.$inner, buf)
+                }
+
+                #[inline(always)]
+                fn write_vectored(&mut self, bufs: &[$crate::__std::io::IoSlice<'_>]) -> $crate::__std::io::Result<usize> {
+                    $crate::__std::io::Write::write_vectored(&mut self.$inner, bufs)
+                }
+
+                #[inline(always)]
+                fn flush(&mut self) -> $crate::__std::io::Result<()> {
+                    $crate::__std::io::Write::flush(&mut self.$inner)
+                }
+
+                #[inline(always)]
+                fn write_all(&mut self, buf: &[u8]) -> $crate::__std::io::Result<()> {
+                    $crate::__std::io::Write::write_all(&mut self.$inner, buf)
+                }
+
+                #[inline(always)]
+                fn write_fmt(&mut self, fmt: ::core::fmt::Arguments<'_>) -> $crate::__std::io::Result<()> {
+                    $crate::__std::io::Write::write_fmt(&mut self.$inner, fmt)
+                }
+            }
+        }
+    };
+    // ================ Impl `io::Write` trait for the wrapper type. ================
+
+    // ================ Impl `io::Seek` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_IO_SEEK
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        $crate::__cfg_std! {
+            Seek;
+
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__std::io::Seek for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__std::io::Seek,
+            {
+                #[inline(always)]
+                fn seek(&mut self, pos: $crate::__std::io::SeekFrom) -> $crate::__std::io::Result<u64> {
+                    $crate::__std::io::Seek::seek(&mut self.inner, pos)
+                }
+
+                #[inline(always)]
+                fn stream_position(&mut self) -> $crate::__std::io::Result<u64> {
+                    $crate::__std::io::Seek::stream_position(&mut self.inner)
+                }
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_IO_SEEK
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
             $(
-                $(#[$field_meta])*
-                $field_vis $field: $field_ty
-            ),*
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
         }
+    ) => {
+        $crate::__cfg_std! {
+            Seek;
 
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
-            #[inline(always)]
-            #[doc = concat!("Creates a new instance of [`", stringify!($name), "`]")]
-            $inner_vis const fn const_from($inner: $inner_ty) -> Self {
-                Self {
-                    $inner,
-                    $(
-                        $field: $field_default,
-                    )*
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__std::io::Seek for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__std::io::Seek,
+            {
+                #[inline(always)]
+                fn seek(&mut self, pos: $crate::__std::io::SeekFrom) -> $crate::__std::io::Result<u64> {
+                    $crate::__std::io::Seek::seek(&mut self.$inner, pos)
+                }
+
+                #[inline(always)]
+                fn stream_position(&mut self) -> $crate::__std::io::Result<u64> {
+                    $crate::__std::io::Seek::stream_position(&mut self.$inner)
                 }
             }
         }
     };
+    // ================ Impl `io::Seek` trait for the wrapper type. ================
 
-    // The actual implementation of the wrapper type with fields: `pub struct Name<...> { ... }`
+    // ================ Impl `io::BufRead` trait for the wrapper type. ================
     (
-        @INTERNAL IMPL
-        #[repr(align(cache))]
-        $(#[$outer:meta])*
+        @INTERNAL WRAPPER_IMPL_IO_BUF_READ
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        $crate::__cfg_std! {
+            BufRead;
+
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__std::io::BufRead for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__std::io::BufRead,
+            {
+                #[inline(always)]
+                fn fill_buf(&mut self) -> $crate::__std::io::Result<&[u8]> {
+                    $crate::__std::io::BufRead::fill_buf(&mut self.inner)
+                }
+
+                #[inline(always)]
+                fn consume(&mut self, amt: usize) {
+                    $crate::__std::io::BufRead::consume(&mut self.inner, amt)
+                }
+
+                #[inline(always)]
+                fn read_until(&mut self, byte: u8, buf: &mut $crate::__std::vec::Vec<u8>) -> $crate::__std::io::Result<usize> {
+                    $crate::__std::io::BufRead::read_until(&mut self.inner, byte, buf)
+                }
+
+                #[inline(always)]
+                fn read_line(&mut self, buf: &mut $crate::__std::string::String) -> $crate::__std::io::Result<usize> {
+                    $crate::__std::io::BufRead::read_line(&mut self.inner, buf)
+                }
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_IO_BUF_READ
+        $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
             $(#[$field_inner_meta:meta])*
             $inner_vis:vis $inner:ident: $inner_ty:ty
             $(
                 ,
                 $(#[$field_meta:meta])*
-                $field_vis:vis $field:ident: $field_ty:ty
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
             )*
             $(,)?
         }
     ) => {
-        // Starting from Intel's Sandy Bridge, spatial prefetcher is now pulling pairs of 64-byte cache
-        // lines at a time, so we have to align to 128 bytes rather than 64.
-        //
-        // Sources:
-        // - https://www.intel.com/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-optimization-manual.pdf
-        // - https://github.com/facebook/folly/blob/1b5288e6eea6df074758f877c849b6e73bbb9fbb/folly/lang/Align.h#L107
-        //
-        // aarch64/arm64ec's big.LITTLE architecture has asymmetric cores and "big" cores have 128-byte cache line size.
-        //
-        // Sources:
-        // - https://www.mono-project.com/news/2016/09/12/arm64-icache/
-        //
-        // powerpc64 has 128-byte cache line size.
-        //
-        // Sources:
-        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_ppc64x.go#L9
-        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/powerpc/include/asm/cache.h#L26
-        #[cfg_attr(
-            any(
-                target_arch = "x86_64",
-                target_arch = "aarch64",
-                target_arch = "arm64ec",
-                target_arch = "powerpc64",
-            ),
-            repr(align(128))
-        )]
-        // arm, mips, mips64, sparc, and hexagon have 32-byte cache line size.
-        //
-        // Sources:
-        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_arm.go#L7
-        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_mips.go#L7
-        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_mipsle.go#L7
-        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_mips64x.go#L9
-        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/sparc/include/asm/cache.h#L17
-        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/hexagon/include/asm/cache.h#L12
-        #[cfg_attr(
-            any(
-                target_arch = "arm",
-                target_arch = "mips",
-                target_arch = "mips32r6",
-                target_arch = "mips64",
-                target_arch = "mips64r6",
-                target_arch = "sparc",
-                target_arch = "hexagon",
-            ),
-            repr(align(32))
-        )]
-        // m68k has 16-byte cache line size.
-        //
-        // Sources:
-        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/m68k/include/asm/cache.h#L9
-        #[cfg_attr(target_arch = "m68k", repr(align(16)))]
-        // s390x has 256-byte cache line size.
-        //
-        // Sources:
-        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_s390x.go#L7
-        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/s390/include/asm/cache.h#L13
-        #[cfg_attr(target_arch = "s390x", repr(align(256)))]
-        // x86, wasm, riscv, and sparc64 have 64-byte cache line size.
-        //
-        // Sources:
-        // - https://github.com/golang/go/blob/dda2991c2ea0c5914714469c4defc2562a907230/src/internal/cpu/cpu_x86.go#L9
-        // - https://github.com/golang/go/blob/3dd58676054223962cd915bb0934d1f9f489d4d2/src/internal/cpu/cpu_wasm.go#L7
-        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/riscv/include/asm/cache.h#L10
-        // - https://github.com/torvalds/linux/blob/3516bd729358a2a9b090c1905bd2a3fa926e24c6/arch/sparc/include/asm/cache.h#L19
-        //
-        // All others are assumed to have 64-byte cache line size.
-        #[cfg_attr(
-            not(any(
-                target_arch = "x86_64",
-                target_arch = "aarch64",
-                target_arch = "arm64ec",
-                target_arch = "powerpc64",
-                target_arch = "arm",
-                target_arch = "mips",
-                target_arch = "mips32r6",
-                target_arch = "mips64",
-                target_arch = "mips64r6",
-                target_arch = "sparc",
-                target_arch = "hexagon",
-                target_arch = "m68k",
-                target_arch = "s390x",
-            )),
-            repr(align(64))
-        )]
-        $(#[$outer])*
-        $vis struct $name$(<$($lt),+>)? {
-            $(#[$field_inner_meta])*
-            $inner_vis $inner: $inner_ty
+        $crate::__cfg_std! {
+            BufRead;
+
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__std::io::BufRead for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__std::io::BufRead,
+            {
+                #[inline(always)]
+                fn fill_buf(&mut self) -> $crate::__std::io::Result<&[u8]> {
+                    $crate::__std::io::BufRead::fill_buf(&mut self.$inner)
+                }
+
+                #[inline(always)]
+                fn consume(&mut self, amt: usize) {
+                    $crate::__std::io::BufRead::consume(&mut self.$inner, amt)
+                }
+
+                #[inline(always)]
+                fn read_until(&mut self, byte: u8, buf: &mut $crate::__std::vec::Vec<u8>) -> $crate::__std::io::Result<usize> {
+                    $crate::__std::io::BufRead::read_until(&mut self.$inner, byte, buf)
+                }
+
+                #[inline(always)]
+                fn read_line(&mut self, buf: &mut $crate::__std::string::String) -> $crate::__std::io::Result<usize> {
+                    $crate::__std::io::BufRead::read_line(&mut self.$inner, buf)
+                }
+            }
+        }
+    };
+    // ================ Impl `io::BufRead` trait for the wrapper type. ================
+
+    // ================ Impl `fmt::Write` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_FMT_WRITE
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::fmt::Write for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::fmt::Write,
+        {
+            #[inline(always)]
+            fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+                ::core::fmt::Write::write_str(&mut self.inner, s)
+            }
+
+            #[inline(always)]
+            fn write_char(&mut self, c: char) -> ::core::fmt::Result {
+                ::core::fmt::Write::write_char(&mut self.inner, c)
+            }
+
+            #[inline(always)]
+            fn write_fmt(&mut self, args: ::core::fmt::Arguments<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Write::write_fmt(&mut self.inner, args)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_FMT_WRITE
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
             $(
                 ,
-                $(#[$field_meta])*
-                $field_vis $field: $field_ty
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
             )*
+            $(,)?
+        }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::fmt::Write for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::fmt::Write,
+        {
+            #[inline(always)]
+            fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+                ::core::fmt::Write::write_str(&mut self.$inner, s)
+            }
+
+            #[inline(always)]
+            fn write_char(&mut self, c: char) -> ::core::fmt::Result {
+                ::core::fmt::Write::write_char(&mut self.$inner, c)
+            }
+
+            #[inline(always)]
+            fn write_fmt(&mut self, args: ::core::fmt::Arguments<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Write::write_fmt(&mut self.$inner, args)
+            }
+        }
+    };
+    // ================ Impl `fmt::Write` trait for the wrapper type. ================
+
+    // ================ Impl pinning guards for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_PIN_GUARD
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_PIN_GUARD
+            $vis struct $name$(<$($lt$(:$clt$(+$dlt)*)?),+>)? {
+                $inner_vis inner: $inner_ty
+            }
         }
     };
-
     (
-        @INTERNAL IMPL
-        $(#[$outer:meta])*
+        @INTERNAL WRAPPER_IMPL_PIN_GUARD
+        $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
             $(#[$field_inner_meta:meta])*
             $inner_vis:vis $inner:ident: $inner_ty:ty
             $(
                 ,
                 $(#[$field_meta:meta])*
-                $field_vis:vis $field:ident: $field_ty:ty
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
             )*
             $(,)?
         }
     ) => {
-        $(#[$outer])*
-        $vis struct $name$(<$($lt),+>)? {
-            $(#[$field_inner_meta])*
-            $inner_vis $inner: $inner_ty
-            $(
-                ,
-                $(#[$field_meta])*
-                $field_vis $field: $field_ty
-            )*
+        // The inner field is structurally pinned, so the wrapper type is `Unpin`
+        // only if the inner type is. The extra lifetime keeps the bound from
+        // being a trivial one when the inner type is a concrete `!Unpin` type.
+        impl<'__pin $(, $($lt$(:$clt$(+$dlt)*)?),+)?> ::core::marker::Unpin for $name$(<$($lt),+>)?
+        where
+            (::core::marker::PhantomData<&'__pin ()>, $inner_ty): ::core::marker::Unpin,
+        {
         }
-    };
 
-    // === Process all `wrapper_impl` attributes, and generate impls. ===
+        // A `Drop` impl could move the inner field out of a pinned wrapper, so
+        // implementing `Drop` for the wrapper type is rejected with a conflict.
+        const _: () = {
+            trait __WrapperMustNotImplDrop {}
 
-    // Extract wrapper impl for `AsRef` trait.
+            #[allow(drop_bounds)]
+            impl<T: ::core::ops::Drop> __WrapperMustNotImplDrop for T {}
+
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? __WrapperMustNotImplDrop for $name$(<$($lt),+>)? {}
+        };
+    };
+    // ================ Impl pinning guards for the wrapper type. ================
+
+    // ================ Impl pin projection methods for the wrapper type. ================
     (
-        @INTERNAL WRAPPER_IMPL
-        #[wrapper_impl(AsRef $(<$target:ty>)? )]
-        $($tt:tt)*
+        @INTERNAL WRAPPER_IMPL_PIN_PROJECT
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
         $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL_AS_REF $(<$target>)?
-            $($tt)*
+            @INTERNAL WRAPPER_IMPL_PIN_PROJECT
+            $vis struct $name$(<$($lt$(:$clt$(+$dlt)*)?),+>)? {
+                $inner_vis inner: $inner_ty
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_PIN_PROJECT
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
         }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
+            /// Returns a pinned reference to the inner value.
+            #[inline(always)]
+            pub fn as_inner_pin(self: ::core::pin::Pin<&Self>) -> ::core::pin::Pin<&$inner_ty> {
+                // SAFETY: the inner field is structurally pinned, see
+                // `WRAPPER_IMPL_PIN_GUARD`.
+                unsafe { self.map_unchecked(|this| &this.$inner) }
+            }
 
-        $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL
-            $($tt)*
+            /// Returns a pinned mutable reference to the inner value.
+            #[inline(always)]
+            pub fn as_inner_pin_mut(self: ::core::pin::Pin<&mut Self>) -> ::core::pin::Pin<&mut $inner_ty> {
+                // SAFETY: the inner field is structurally pinned, see
+                // `WRAPPER_IMPL_PIN_GUARD`.
+                unsafe { self.map_unchecked_mut(|this| &mut this.$inner) }
+            }
         }
     };
+    // ================ Impl pin projection methods for the wrapper type. ================
 
-    // Extract wrapper impl for `AsMut` trait.
+    // ================ Impl `Future` trait for the wrapper type. ================
     (
-        @INTERNAL WRAPPER_IMPL
-        #[wrapper_impl(AsMut $(<$target:ty>)? )]
-        $($tt:tt)*
+        @INTERNAL WRAPPER_IMPL_FUTURE
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
         $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL_AS_MUT $(<$target>)?
-            $($tt)*
+            @INTERNAL WRAPPER_IMPL_FUTURE
+            $vis struct $name$(<$($lt$(:$clt$(+$dlt)*)?),+>)? {
+                $inner_vis inner: $inner_ty
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_FUTURE
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
         }
+    ) => {
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::future::Future for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::future::Future,
+        {
+            type Output = <$inner_ty as ::core::future::Future>::Output;
 
-        $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL
-            $($tt)*
+            #[inline(always)]
+            fn poll(
+                self: ::core::pin::Pin<&mut Self>,
+                cx: &mut ::core::task::Context<'_>,
+            ) -> ::core::task::Poll<Self::Output> {
+                ::core::future::Future::poll(self.as_inner_pin_mut(), cx)
+            }
         }
     };
+    // ================ Impl `Future` trait for the wrapper type. ================
 
-    // Extract wrapper impl for `AsMut` trait, const version.
+    // ================ Impl `tokio::io::AsyncRead` trait for the wrapper type. ================
     (
-        @INTERNAL WRAPPER_IMPL
-        #[wrapper_impl(ConstAsMut $(<$target:ty>)? )]
-        $($tt:tt)*
+        @INTERNAL WRAPPER_IMPL_TOKIO_ASYNC_READ
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
-        $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL_CONST_AS_MUT $(<$target>)?
-            $($tt)*
-        }
+        $crate::__cfg_tokio! {
+            TokioAsyncRead;
 
-        $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL
-            $($tt)*
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__tokio::io::AsyncRead for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__tokio::io::AsyncRead,
+            {
+                #[inline(always)]
+                fn poll_read(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                    buf: &mut $crate::__tokio::io::ReadBuf<'_>,
+                ) -> ::core::task::Poll<$crate::__std::io::Result<()>> {
+                    $crate::__tokio::io::AsyncRead::poll_read(self.as_inner_pin_mut(), cx, buf)
+                }
+            }
         }
     };
-
-    // Extract wrapper impl for `Borrow` trait.
     (
-        @INTERNAL WRAPPER_IMPL
-        #[wrapper_impl(Borrow $(<$target:ty>)? )]
-        $($tt:tt)*
-    ) => {
-        $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL_BORROW $(<$target>)?
-            $($tt)*
+        @INTERNAL WRAPPER_IMPL_TOKIO_ASYNC_READ
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
         }
+    ) => {
+        $crate::__cfg_tokio! {
+            TokioAsyncRead;
 
-        $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL
-            $($tt)*
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__tokio::io::AsyncRead for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__tokio::io::AsyncRead,
+            {
+                #[inline(always)]
+                fn poll_read(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                    buf: &mut $crate::__tokio::io::ReadBuf<'_>,
+                ) -> ::core::task::Poll<$crate::__std::io::Result<()>> {
+                    $crate::__tokio::io::AsyncRead::poll_read(self.as_inner_pin_mut(), cx, buf)
+                }
+            }
         }
     };
+    // ================ Impl `tokio::io::AsyncRead` trait for the wrapper type. ================
 
-    // Extract wrapper impl for `BorrowMut` trait.
+    // ================ Impl `tokio::io::AsyncWrite` trait for the wrapper type. ================
     (
-        @INTERNAL WRAPPER_IMPL
-        #[wrapper_impl(BorrowMut $(<$target:ty>)? )]
-        $($tt:tt)*
+        @INTERNAL WRAPPER_IMPL_TOKIO_ASYNC_WRITE
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
-        $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL_BORROW $(<$target>)?
-            $($tt)*
-        }
+        $crate::__cfg_tokio! {
+            TokioAsyncWrite;
 
-        $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL_BORROW_MUT $(<$target>)?
-            $($tt)*
-        }
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__tokio::io::AsyncWrite for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__tokio::io::AsyncWrite,
+            {
+                #[inline(always)]
+                fn poll_write(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                    buf: &[u8],
+                ) -> ::core::task::Poll<$crate::__std::io::Result<usize>> {
+                    $crate::__tokio::io::AsyncWrite::poll_write(self.as_inner_pin_mut(), cx, buf)
+                }
 
-        $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL
-            $($tt)*
+                #[inline(always)]
+                fn poll_flush(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                ) -> ::core::task::Poll<$crate::__std::io::Result<()>> {
+                    $crate::__tokio::io::AsyncWrite::poll_flush(self.as_inner_pin_mut(), cx)
+                }
+
+                #[inline(always)]
+                fn poll_shutdown(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                ) -> ::core::task::Poll<$crate::__std::io::Result<()>> {
+                    $crate::__tokio::io::AsyncWrite::poll_shutdown(self.as_inner_pin_mut(), cx)
+                }
+
+                #[inline(always)]
+                fn poll_write_vectored(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                    bufs: &[$crate::__std::io::IoSlice<'_>],
+                ) -> ::core::task::Poll<$crate::__std::io::Result<usize>> {
+                    $crate::__tokio::io::AsyncWrite::poll_write_vectored(self.as_inner_pin_mut(), cx, bufs)
+                }
+
+                #[inline(always)]
+                fn is_write_vectored(&self) -> bool {
+                    $crate::__tokio::io::AsyncWrite::is_write_vectored(&self.inner)
+                }
+            }
         }
     };
-
-    // Extract wrapper impl for `Debug` trait.
     (
-        @INTERNAL WRAPPER_IMPL
-        #[wrapper_impl(Debug)]
-        $($tt:tt)*
-    ) => {
-        $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL_DEBUG
-            $($tt)*
+        @INTERNAL WRAPPER_IMPL_TOKIO_ASYNC_WRITE
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
         }
+    ) => {
+        $crate::__cfg_tokio! {
+            TokioAsyncWrite;
 
-        $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL
-            $($tt)*
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__tokio::io::AsyncWrite for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__tokio::io::AsyncWrite,
+            {
+                #[inline(always)]
+                fn poll_write(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                    buf: &[u8],
+                ) -> ::core::task::Poll<$crate::__std::io::Result<usize>> {
+                    $crate::__tokio::io::AsyncWrite::poll_write(self.as_inner_pin_mut(), cx, buf)
+                }
+
+                #[inline(always)]
+                fn poll_flush(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                ) -> ::core::task::Poll<$crate::__std::io::Result<()>> {
+                    $crate::__tokio::io::AsyncWrite::poll_flush(self.as_inner_pin_mut(), cx)
+                }
+
+                #[inline(always)]
+                fn poll_shutdown(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                ) -> ::core::task::Poll<$crate::__std::io::Result<()>> {
+                    $crate::__tokio::io::AsyncWrite::poll_shutdown(self.as_inner_pin_mut(), cx)
+                }
+
+                #[inline(always)]
+                fn poll_write_vectored(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                    bufs: &[$crate::__std::io::IoSlice<'_>],
+                ) -> ::core::task::Poll<$crate::__std::io::Result<usize>> {
+                    $crate::__tokio::io::AsyncWrite::poll_write_vectored(self.as_inner_pin_mut(), cx, bufs)
+                }
+
+                #[inline(always)]
+                fn is_write_vectored(&self) -> bool {
+                    $crate::__tokio::io::AsyncWrite::is_write_vectored(&self.$inner)
+                }
+            }
         }
     };
+    // ================ Impl `tokio::io::AsyncWrite` trait for the wrapper type. ================
 
-    // Extract wrapper impl for `Debug` trait  printing its name only.
+    // ================ Impl `tokio::io::AsyncBufRead` trait for the wrapper type. ================
     (
-        @INTERNAL WRAPPER_IMPL
-        #[wrapper_impl(DebugName)]
-        $($tt:tt)*
+        @INTERNAL WRAPPER_IMPL_TOKIO_ASYNC_BUF_READ
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
-        $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL_DEBUG_NAME
-            $($tt)*
+        $crate::__cfg_tokio! {
+            TokioAsyncBufRead;
+
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__tokio::io::AsyncBufRead for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__tokio::io::AsyncBufRead,
+            {
+                #[inline(always)]
+                fn poll_fill_buf(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                ) -> ::core::task::Poll<$crate::__std::io::Result<&[u8]>> {
+                    $crate::__tokio::io::AsyncBufRead::poll_fill_buf(self.as_inner_pin_mut(), cx)
+                }
+
+                #[inline(always)]
+                fn consume(self: ::core::pin::Pin<&mut Self>, amt: usize) {
+                    $crate::__tokio::io::AsyncBufRead::consume(self.as_inner_pin_mut(), amt)
+                }
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_TOKIO_ASYNC_BUF_READ
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
         }
+    ) => {
+        $crate::__cfg_tokio! {
+            TokioAsyncBufRead;
 
-        $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL
-            $($tt)*
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__tokio::io::AsyncBufRead for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__tokio::io::AsyncBufRead,
+            {
+                #[inline(always)]
+                fn poll_fill_buf(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                ) -> ::core::task::Poll<$crate::__std::io::Result<&[u8]>> {
+                    $crate::__tokio::io::AsyncBufRead::poll_fill_buf(self.as_inner_pin_mut(), cx)
+                }
+
+                #[inline(always)]
+                fn consume(self: ::core::pin::Pin<&mut Self>, amt: usize) {
+                    $crate::__tokio::io::AsyncBufRead::consume(self.as_inner_pin_mut(), amt)
+                }
+            }
         }
     };
+    // ================ Impl `tokio::io::AsyncBufRead` trait for the wrapper type. ================
 
-    // Extract wrapper impl for `Display` trait.
+    // ================ Impl `tokio::io::AsyncSeek` trait for the wrapper type. ================
     (
-        @INTERNAL WRAPPER_IMPL
-        #[wrapper_impl(Display)]
-        $($tt:tt)*
+        @INTERNAL WRAPPER_IMPL_TOKIO_ASYNC_SEEK
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
-        $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL_DISPLAY
-            $($tt)*
+        $crate::__cfg_tokio! {
+            TokioAsyncSeek;
+
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__tokio::io::AsyncSeek for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__tokio::io::AsyncSeek,
+            {
+                #[inline(always)]
+                fn start_seek(
+                    self: ::core::pin::Pin<&mut Self>,
+                    position: $crate::__std::io::SeekFrom,
+                ) -> $crate::__std::io::Result<()> {
+                    $crate::__tokio::io::AsyncSeek::start_seek(self.as_inner_pin_mut(), position)
+                }
+
+                #[inline(always)]
+                fn poll_complete(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                ) -> ::core::task::Poll<$crate::__std::io::Result<u64>> {
+                    $crate::__tokio::io::AsyncSeek::poll_complete(self.as_inner_pin_mut(), cx)
+                }
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_TOKIO_ASYNC_SEEK
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
         }
+    ) => {
+        $crate::__cfg_tokio! {
+            TokioAsyncSeek;
 
-        $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL
-            $($tt)*
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__tokio::io::AsyncSeek for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__tokio::io::AsyncSeek,
+            {
+                #[inline(always)]
+                fn start_seek(
+                    self: ::core::pin::Pin<&mut Self>,
+                    position: $crate::__std::io::SeekFrom,
+                ) -> $crate::__std::io::Result<()> {
+                    $crate::__tokio::io::AsyncSeek::start_seek(self.as_inner_pin_mut(), position)
+                }
+
+                #[inline(always)]
+                fn poll_complete(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                ) -> ::core::task::Poll<$crate::__std::io::Result<u64>> {
+                    $crate::__tokio::io::AsyncSeek::poll_complete(self.as_inner_pin_mut(), cx)
+                }
+            }
         }
     };
+    // ================ Impl `tokio::io::AsyncSeek` trait for the wrapper type. ================
 
-    // Extract wrapper impl for `Deref` trait.
+    // ================ Impl `futures_io::AsyncRead` trait for the wrapper type. ================
     (
-        @INTERNAL WRAPPER_IMPL
-        #[wrapper_impl(Deref $(<$target:ty>)? )]
-        $($tt:tt)*
+        @INTERNAL WRAPPER_IMPL_FUTURES_ASYNC_READ
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
-        $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL_DEREF $(<$target>)?
-            $($tt)*
-        }
+        $crate::__cfg_futures_io! {
+            FuturesAsyncRead;
 
-        $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL
-            $($tt)*
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__futures_io::AsyncRead for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__futures_io::AsyncRead,
+            {
+                #[inline(always)]
+                fn poll_read(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                    buf: &mut [u8],
+                ) -> ::core::task::Poll<$crate::__std::io::Result<usize>> {
+                    $crate::__futures_io::AsyncRead::poll_read(self.as_inner_pin_mut(), cx, buf)
+                }
+
+                #[inline(always)]
+                fn poll_read_vectored(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                    bufs: &mut [$crate::__std::io::IoSliceMut<'_>],
+                ) -> ::core::task::Poll<$crate::__std::io::Result<usize>> {
+                    $crate::__futures_io::AsyncRead::poll_read_vectored(self.as_inner_pin_mut(), cx, bufs)
+                }
+            }
         }
     };
-
-    // Extract wrapper impl for `DerefMut` trait (and `Deref`).
     (
-        @INTERNAL WRAPPER_IMPL
-        #[wrapper_impl(DerefMut $(<$target:ty>)? )]
-        $($tt:tt)*
-    ) => {
-        $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL_DEREF $(<$target>)?
-            $($tt)*
+        @INTERNAL WRAPPER_IMPL_FUTURES_ASYNC_READ
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
         }
+    ) => {
+        $crate::__cfg_futures_io! {
+            FuturesAsyncRead;
 
-        $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL_DEREF_MUT $(<$target>)?
-            $($tt)*
-        }
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__futures_io::AsyncRead for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__futures_io::AsyncRead,
+            {
+                #[inline(always)]
+                fn poll_read(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                    buf: &mut [u8],
+                ) -> ::core::task::Poll<$crate::__std::io::Result<usize>> {
+                    $crate::__futures_io::AsyncRead::poll_read(self.as_inner_pin_mut(), cx, buf)
+                }
 
-        $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL
-            $($tt)*
+                #[inline(always)]
+                fn poll_read_vectored(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                    bufs: &mut [$crate::__std::io::IoSliceMut<'_>],
+                ) -> ::core::task::Poll<$crate::__std::io::Result<usize>> {
+                    $crate::__futures_io::AsyncRead::poll_read_vectored(self.as_inner_pin_mut(), cx, bufs)
+                }
+            }
         }
     };
+    // ================ Impl `futures_io::AsyncRead` trait for the wrapper type. ================
 
-    // Extract wrapper impl for `From` trait.
+    // ================ Impl `futures_io::AsyncWrite` trait for the wrapper type. ================
     (
-        @INTERNAL WRAPPER_IMPL
-        #[wrapper_impl(From)]
-        $($tt:tt)*
+        @INTERNAL WRAPPER_IMPL_FUTURES_ASYNC_WRITE
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
-        $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL_FROM
-            $($tt)*
+        $crate::__cfg_futures_io! {
+            FuturesAsyncWrite;
+
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__futures_io::AsyncWrite for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__futures_io::AsyncWrite,
+            {
+                #[inline(always)]
+                fn poll_write(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                    buf: &[u8],
+                ) -> ::core::task::Poll<$crate::__std::io::Result<usize>> {
+                    $crate::__futures_io::AsyncWrite::poll_write(self.as_inner_pin_mut(), cx, buf)
+                }
+
+                #[inline(always)]
+                fn poll_write_vectored(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                    bufs: &[$crate::__std::io::IoSlice<'_>],
+                ) -> ::core::task::Poll<$crate::__std::io::Result<usize>> {
+                    $crate::__futures_io::AsyncWrite::poll_write_vectored(self.as_inner_pin_mut(), cx, bufs)
+                }
+
+                #[inline(always)]
+                fn poll_flush(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                ) -> ::core::task::Poll<$crate::__std::io::Result<()>> {
+                    $crate::__futures_io::AsyncWrite::poll_flush(self.as_inner_pin_mut(), cx)
+                }
+
+                #[inline(always)]
+                fn poll_close(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                ) -> ::core::task::Poll<$crate::__std::io::Result<()>> {
+                    $crate::__futures_io::AsyncWrite::poll_close(self.as_inner_pin_mut(), cx)
+                }
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_FUTURES_ASYNC_WRITE
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
         }
+    ) => {
+        $crate::__cfg_futures_io! {
+            FuturesAsyncWrite;
 
-        $crate::wrapper! {
-            @INTERNAL WRAPPER_IMPL
-            $($tt)*
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__futures_io::AsyncWrite for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__futures_io::AsyncWrite,
+            {
+                #[inline(always)]
+                fn poll_write(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                    buf: &[u8],
+                ) -> ::core::task::Poll<$crate::__std::io::Result<usize>> {
+                    $crate::__futures_io::AsyncWrite::poll_write(self.as_inner_pin_mut(), cx, buf)
+                }
+
+                #[inline(always)]
+                fn poll_write_vectored(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                    bufs: &[$crate::__std::io::IoSlice<'_>],
+                ) -> ::core::task::Poll<$crate::__std::io::Result<usize>> {
+                    $crate::__futures_io::AsyncWrite::poll_write_vectored(self.as_inner_pin_mut(), cx, bufs)
+                }
+
+                #[inline(always)]
+                fn poll_flush(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                ) -> ::core::task::Poll<$crate::__std::io::Result<()>> {
+                    $crate::__futures_io::AsyncWrite::poll_flush(self.as_inner_pin_mut(), cx)
+                }
+
+                #[inline(always)]
+                fn poll_close(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                ) -> ::core::task::Poll<$crate::__std::io::Result<()>> {
+                    $crate::__futures_io::AsyncWrite::poll_close(self.as_inner_pin_mut(), cx)
+                }
+            }
         }
     };
+    // ================ Impl `futures_io::AsyncWrite` trait for the wrapper type. ================
 
-    // ================ Impl `AsRef` trait for the wrapper type. ================
+    // ================ Impl `futures_io::AsyncBufRead` trait for the wrapper type. ================
     (
-        @INTERNAL WRAPPER_IMPL_AS_REF <$target:ty>
+        @INTERNAL WRAPPER_IMPL_FUTURES_ASYNC_BUF_READ
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsRef<$target> for $name$(<$($lt),+>)? {
-            fn as_ref(&self) -> &$target {
-                &self.inner
+        $crate::__cfg_futures_io! {
+            FuturesAsyncBufRead;
+
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__futures_io::AsyncBufRead for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__futures_io::AsyncBufRead,
+            {
+                #[inline(always)]
+                fn poll_fill_buf(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                ) -> ::core::task::Poll<$crate::__std::io::Result<&[u8]>> {
+                    $crate::__futures_io::AsyncBufRead::poll_fill_buf(self.as_inner_pin_mut(), cx)
+                }
+
+                #[inline(always)]
+                fn consume(self: ::core::pin::Pin<&mut Self>, amt: usize) {
+                    $crate::__futures_io::AsyncBufRead::consume(self.as_inner_pin_mut(), amt)
+                }
             }
         }
     };
     (
-        @INTERNAL WRAPPER_IMPL_AS_REF <$target:ty>
+        @INTERNAL WRAPPER_IMPL_FUTURES_ASYNC_BUF_READ
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
             $(#[$field_inner_meta:meta])*
@@ -1052,33 +9248,56 @@ macro_rules! wrapper {
             $(,)?
         }
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsRef<$target> for $name$(<$($lt),+>)? {
-            fn as_ref(&self) -> &$target {
-                &self.$inner
+        $crate::__cfg_futures_io! {
+            FuturesAsyncBufRead;
+
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__futures_io::AsyncBufRead for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__futures_io::AsyncBufRead,
+            {
+                #[inline(always)]
+                fn poll_fill_buf(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                ) -> ::core::task::Poll<$crate::__std::io::Result<&[u8]>> {
+                    $crate::__futures_io::AsyncBufRead::poll_fill_buf(self.as_inner_pin_mut(), cx)
+                }
+
+                #[inline(always)]
+                fn consume(self: ::core::pin::Pin<&mut Self>, amt: usize) {
+                    $crate::__futures_io::AsyncBufRead::consume(self.as_inner_pin_mut(), amt)
+                }
             }
         }
     };
+    // ================ Impl `futures_io::AsyncBufRead` trait for the wrapper type. ================
+
+    // ================ Impl `futures_io::AsyncSeek` trait for the wrapper type. ================
     (
-        @INTERNAL WRAPPER_IMPL_AS_REF
+        @INTERNAL WRAPPER_IMPL_FUTURES_ASYNC_SEEK
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsRef<$inner_ty> for $name$(<$($lt),+>)? {
-            fn as_ref(&self) -> &$inner_ty {
-                &self.inner
-            }
-        }
+        $crate::__cfg_futures_io! {
+            FuturesAsyncSeek;
 
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
-            /// Returns a reference to the inner value.
-            #[inline(always)]
-            pub const fn as_inner(&self) -> &$inner_ty {
-                &self.inner
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__futures_io::AsyncSeek for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__futures_io::AsyncSeek,
+            {
+                #[inline(always)]
+                fn poll_seek(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                    pos: $crate::__std::io::SeekFrom,
+                ) -> ::core::task::Poll<$crate::__std::io::Result<u64>> {
+                    $crate::__futures_io::AsyncSeek::poll_seek(self.as_inner_pin_mut(), cx, pos)
+                }
             }
         }
     };
     (
-        @INTERNAL WRAPPER_IMPL_AS_REF
+        @INTERNAL WRAPPER_IMPL_FUTURES_ASYNC_SEEK
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
             $(#[$field_inner_meta:meta])*
@@ -1091,37 +9310,51 @@ macro_rules! wrapper {
             $(,)?
         }
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsRef<$inner_ty> for $name$(<$($lt),+>)? {
-            fn as_ref(&self) -> &$inner_ty {
-                &self.$inner
-            }
-        }
+        $crate::__cfg_futures_io! {
+            FuturesAsyncSeek;
 
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
-            /// Returns a reference to the inner value.
-            #[inline(always)]
-            pub const fn as_inner(&self) -> &$inner_ty {
-                &self.$inner
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__futures_io::AsyncSeek for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__futures_io::AsyncSeek,
+            {
+                #[inline(always)]
+                fn poll_seek(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                    pos: $crate::__std::io::SeekFrom,
+                ) -> ::core::task::Poll<$crate::__std::io::Result<u64>> {
+                    $crate::__futures_io::AsyncSeek::poll_seek(self.as_inner_pin_mut(), cx, pos)
+                }
             }
         }
     };
-    // ================ Impl `AsRef` trait for the wrapper type. ================
-
+    // ================ Impl `futures_io::AsyncSeek` trait for the wrapper type. ================
 
-    // ================ Impl `AsMut` trait for the wrapper type. ================
+    // ================ Impl `serde::Serialize` trait for the wrapper type. ================
     (
-        @INTERNAL WRAPPER_IMPL_AS_MUT <$target:ty>
+        @INTERNAL WRAPPER_IMPL_SERDE_SERIALIZE
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsMut<$target> for $name$(<$($lt),+>)? {
-            fn as_mut(&mut self) -> &mut $target {
-                &mut self.inner
+        $crate::__cfg_serde! {
+            Serialize;
+
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__serde::Serialize for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__serde::Serialize,
+            {
+                #[inline(always)]
+                fn serialize<__S>(&self, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+                where
+                    __S: $crate::__serde::Serializer,
+                {
+                    $crate::__serde::Serialize::serialize(&self.inner, serializer)
+                }
             }
         }
     };
     (
-        @INTERNAL WRAPPER_IMPL_AS_MUT <$target:ty>
+        @INTERNAL WRAPPER_IMPL_SERDE_SERIALIZE
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
             $(#[$field_inner_meta:meta])*
@@ -1134,34 +9367,51 @@ macro_rules! wrapper {
             $(,)?
         }
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsMut<$target> for $name$(<$($lt),+>)? {
-            #[inline(always)]
-            fn as_mut(&mut self) -> &mut $target {
-                &mut self.$inner
+        $crate::__cfg_serde! {
+            Serialize;
+
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__serde::Serialize for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__serde::Serialize,
+            {
+                #[inline(always)]
+                fn serialize<__S>(&self, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+                where
+                    __S: $crate::__serde::Serializer,
+                {
+                    $crate::__serde::Serialize::serialize(&self.$inner, serializer)
+                }
             }
         }
     };
+    // ================ Impl `serde::Serialize` trait for the wrapper type. ================
+
+    // ================ Impl `serde::Deserialize` trait for the wrapper type. ================
     (
-        @INTERNAL WRAPPER_IMPL_AS_MUT
+        @INTERNAL WRAPPER_IMPL_SERDE_DESERIALIZE
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsMut<$inner_ty> for $name$(<$($lt),+>)? {
-            fn as_mut(&mut self) -> &mut $inner_ty {
-                &mut self.inner
-            }
-        }
+        $crate::__cfg_serde! {
+            Deserialize;
 
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
-            #[inline(always)]
-            /// Returns a mutable reference to the inner value.
-            pub fn as_inner_mut(&mut self) -> &mut $inner_ty {
-                &mut self.inner
+            impl<'__de $(, $($lt$(:$clt$(+$dlt)*)?),+)?> $crate::__serde::Deserialize<'__de> for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__serde::Deserialize<'__de>,
+            {
+                #[inline(always)]
+                fn deserialize<__D>(deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+                where
+                    __D: $crate::__serde::Deserializer<'__de>,
+                {
+                    <$inner_ty as $crate::__serde::Deserialize<'__de>>::deserialize(deserializer)
+                        .map(Self::const_from)
+                }
             }
         }
     };
     (
-        @INTERNAL WRAPPER_IMPL_AS_MUT
+        @INTERNAL WRAPPER_IMPL_SERDE_DESERIALIZE
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
             $(#[$field_inner_meta:meta])*
@@ -1169,39 +9419,110 @@ macro_rules! wrapper {
             $(
                 ,
                 $(#[$field_meta:meta])*
-                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+                $field_vis:vis $field:ident: $field_ty:ty = $field_default:expr
             )*
             $(,)?
         }
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsMut<$inner_ty> for $name$(<$($lt),+>)? {
-            #[inline(always)]
-            fn as_mut(&mut self) -> &mut $inner_ty {
-                &mut self.$inner
-            }
-        }
+        $crate::__cfg_serde! {
+            Deserialize;
 
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
-            #[inline(always)]
-            /// Returns a mutable reference to the inner value.
-            pub fn as_inner_mut(&mut self) -> &mut $inner_ty {
-                &mut self.$inner
+            impl<'__de $(, $($lt$(:$clt$(+$dlt)*)?),+)?> $crate::__serde::Deserialize<'__de> for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__serde::Deserialize<'__de>,
+            {
+                #[inline(always)]
+                fn deserialize<__D>(deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+                where
+                    __D: $crate::__serde::Deserializer<'__de>,
+                {
+                    <$inner_ty as $crate::__serde::Deserialize<'__de>>::deserialize(deserializer)
+                        .map(Self::const_from)
+                }
             }
         }
     };
     (
-        @INTERNAL WRAPPER_IMPL_CONST_AS_MUT <$target:ty>
+        @INTERNAL WRAPPER_IMPL_SERDE_DESERIALIZE
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty
+            )*
+            $(,)?
+        }
+    ) => {
+        compile_error!(
+            "Invalid usage of `wrapper!` macro, cannot implement \
+            `Deserialize` trait for wrapper types with multiple fields \
+            but no default values given."
+        );
+    };
+    // ================ Impl `serde::Deserialize` trait for the wrapper type. ================
+
+    // ================ Impl `serde::Serialize` and `serde::Deserialize` traits as a string for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_SERDE_AS_STRING
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsMut<$target> for $name$(<$($lt),+>)? {
-            fn as_mut(&mut self) -> &mut $target {
-                &mut self.inner
+        $crate::__cfg_serde! {
+            SerdeAsString;
+
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__serde::Serialize for $name$(<$($lt),+>)?
+            where
+                Self: ::core::fmt::Display,
+            {
+                #[inline(always)]
+                fn serialize<__S>(&self, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+                where
+                    __S: $crate::__serde::Serializer,
+                {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'__de $(, $($lt$(:$clt$(+$dlt)*)?),+)?> $crate::__serde::Deserialize<'__de> for $name$(<$($lt),+>)?
+            where
+                Self: ::core::str::FromStr,
+                <Self as ::core::str::FromStr>::Err: ::core::fmt::Display,
+            {
+                fn deserialize<__D>(deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+                where
+                    __D: $crate::__serde::Deserializer<'__de>,
+                {
+                    struct __Visitor<T>(::core::marker::PhantomData<T>);
+
+                    impl<'de, T> $crate::__serde::de::Visitor<'de> for __Visitor<T>
+                    where
+                        T: ::core::str::FromStr,
+                        T::Err: ::core::fmt::Display,
+                    {
+                        type Value = T;
+
+                        fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                            f.write_str("a string")
+                        }
+
+                        fn visit_str<E>(self, v: &str) -> ::core::result::Result<T, E>
+                        where
+                            E: $crate::__serde::de::Error,
+                        {
+                            v.parse().map_err(E::custom)
+                        }
+                    }
+
+                    deserializer.deserialize_str(__Visitor(::core::marker::PhantomData))
+                }
             }
         }
     };
     (
-        @INTERNAL WRAPPER_IMPL_CONST_AS_MUT
+        @INTERNAL WRAPPER_IMPL_SERDE_AS_STRING
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
             $(#[$field_inner_meta:meta])*
@@ -1214,34 +9535,124 @@ macro_rules! wrapper {
             $(,)?
         }
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsMut<$target> for $name$(<$($lt),+>)? {
-            #[inline(always)]
-            fn as_mut(&mut self) -> &mut $target {
-                &mut self.$inner
+        $crate::__cfg_serde! {
+            SerdeAsString;
+
+            impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__serde::Serialize for $name$(<$($lt),+>)?
+            where
+                Self: ::core::fmt::Display,
+            {
+                #[inline(always)]
+                fn serialize<__S>(&self, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+                where
+                    __S: $crate::__serde::Serializer,
+                {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'__de $(, $($lt$(:$clt$(+$dlt)*)?),+)?> $crate::__serde::Deserialize<'__de> for $name$(<$($lt),+>)?
+            where
+                Self: ::core::str::FromStr,
+                <Self as ::core::str::FromStr>::Err: ::core::fmt::Display,
+            {
+                fn deserialize<__D>(deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+                where
+                    __D: $crate::__serde::Deserializer<'__de>,
+                {
+                    struct __Visitor<T>(::core::marker::PhantomData<T>);
+
+                    impl<'de, T> $crate::__serde::de::Visitor<'de> for __Visitor<T>
+                    where
+                        T: ::core::str::FromStr,
+                        T::Err: ::core::fmt::Display,
+                    {
+                        type Value = T;
+
+                        fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                            f.write_str("a string")
+                        }
+
+                        fn visit_str<E>(self, v: &str) -> ::core::result::Result<T, E>
+                        where
+                            E: $crate::__serde::de::Error,
+                        {
+                            v.parse().map_err(E::custom)
+                        }
+                    }
+
+                    deserializer.deserialize_str(__Visitor(::core::marker::PhantomData))
+                }
             }
         }
     };
+    // ================ Impl `serde::Serialize` and `serde::Deserialize` traits as a string for the wrapper type. ================
+
+    // ================ Check that the wrapper type is `repr(transparent)`. ================
     (
-        @INTERNAL WRAPPER_IMPL_CONST_AS_MUT
+        @INTERNAL WRAPPER_IMPL_CHECK_TRANSPARENT $trait:ident
+        #[wrapper_impl($($args:tt)*)]
+        $($tt:tt)*
+    ) => {
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_CHECK_TRANSPARENT $trait
+            $($tt)*
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_CHECK_TRANSPARENT $trait:ident
+        #[repr(align(cache))]
+        $($tt:tt)*
+    ) => {
+        compile_error!(::core::concat!(
+            "Invalid usage of `wrapper!` macro, cannot implement `",
+            ::core::stringify!($trait),
+            "` trait for wrapper types with `repr(align(cache))`, \
+            which are not `repr(transparent)`."
+        ));
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_CHECK_TRANSPARENT $trait:ident
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsMut<$inner_ty> for $name$(<$($lt),+>)? {
-            fn as_mut(&mut self) -> &mut $inner_ty {
-                &mut self.inner
-            }
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_TRANSPARENT $trait
+            $(#[$meta])*
+            $vis struct $name$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ($inner_vis $inner_ty);
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_CHECK_TRANSPARENT $trait:ident
+        $($tt:tt)*
+    ) => {
+        // The named form is made `repr(transparent)` in the `IMPL` pass.
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_TRANSPARENT $trait
+            $($tt)*
         }
+    };
+    // ================ Check that the wrapper type is `repr(transparent)`. ================
 
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
-            #[inline(always)]
-            /// Returns a mutable reference to the inner value.
-            pub const fn as_inner_mut(&mut self) -> &mut $inner_ty {
-                &mut self.inner
+    // ================ Impl `bytemuck` traits for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_TRANSPARENT Zeroable
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        $crate::__cfg_bytemuck! {
+            Zeroable;
+
+            // SAFETY: the wrapper type is `repr(transparent)` over the inner type.
+            unsafe impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__bytemuck::Zeroable for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__bytemuck::Zeroable,
+            {
             }
         }
     };
     (
-        @INTERNAL WRAPPER_IMPL_CONST_AS_MUT
+        @INTERNAL WRAPPER_IMPL_TRANSPARENT Zeroable
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
             $(#[$field_inner_meta:meta])*
@@ -1254,37 +9665,37 @@ macro_rules! wrapper {
             $(,)?
         }
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::AsMut<$inner_ty> for $name$(<$($lt),+>)? {
-            #[inline(always)]
-            fn as_mut(&mut self) -> &mut $inner_ty {
-                &mut self.$inner
-            }
-        }
+        $crate::__cfg_bytemuck! {
+            Zeroable;
 
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
-            #[inline(always)]
-            /// Returns a mutable reference to the inner value.
-            pub const fn as_inner_mut(&mut self) -> &mut $inner_ty {
-                &mut self.$inner
+            // SAFETY: the wrapper type is `repr(transparent)` over the inner type, and
+            // the other fields are ZSTs (checked by the compiler) bounded on `Zeroable`.
+            unsafe impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__bytemuck::Zeroable for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__bytemuck::Zeroable,
+                $($field_ty: $crate::__bytemuck::Zeroable,)*
+            {
             }
         }
     };
-    // ================ Impl `AsMut` trait for the wrapper type. ================
-
-    // ================ Impl `Borrow` trait for the wrapper type. ================
     (
-        @INTERNAL WRAPPER_IMPL_BORROW <$target:ty>
+        @INTERNAL WRAPPER_IMPL_TRANSPARENT Pod
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::borrow::Borrow<$target> for $name$(<$($lt),+>)? {
-            fn borrow(&self) -> &$target {
-                &self.inner
+        $crate::__cfg_bytemuck! {
+            Pod;
+
+            // SAFETY: the wrapper type is `repr(transparent)` over the inner type.
+            unsafe impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__bytemuck::Pod for $name$(<$($lt),+>)?
+            where
+                $inner_ty: $crate::__bytemuck::Pod,
+            {
             }
         }
     };
     (
-        @INTERNAL WRAPPER_IMPL_BORROW <$target:ty>
+        @INTERNAL WRAPPER_IMPL_TRANSPARENT Pod
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
             $(#[$field_inner_meta:meta])*
@@ -1297,25 +9708,34 @@ macro_rules! wrapper {
             $(,)?
         }
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::borrow::Borrow<$target> for $name$(<$($lt),+>)? {
-            fn borrow(&self) -> &$target {
-                &self.$inner
+        $crate::__cfg_bytemuck! {
+            Pod;
+
+            // SAFETY: the wrapper type is `repr(transparent)` over the inner type, and
+            // the other fields are ZSTs (checked by the compiler) bounded on `Pod`.
+            unsafe impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__bytemuck::Pod for $name$(<$($lt),+>)?
+            where
+                Self: ::core::marker::Copy,
+                $inner_ty: $crate::__bytemuck::Pod,
+                $($field_ty: $crate::__bytemuck::Pod,)*
+            {
             }
         }
     };
     (
-        @INTERNAL WRAPPER_IMPL_BORROW
+        @INTERNAL WRAPPER_IMPL_TRANSPARENT TransparentWrapper
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::borrow::Borrow<$inner_ty> for $name$(<$($lt),+>)? {
-            fn borrow(&self) -> &$inner_ty {
-                &self.inner
-            }
+        $crate::__cfg_bytemuck! {
+            TransparentWrapper;
+
+            // SAFETY: the wrapper type is `repr(transparent)` over the inner type.
+            unsafe impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__bytemuck::TransparentWrapper<$inner_ty> for $name$(<$($lt),+>)? {}
         }
     };
     (
-        @INTERNAL WRAPPER_IMPL_BORROW
+        @INTERNAL WRAPPER_IMPL_TRANSPARENT TransparentWrapper
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
             $(#[$field_inner_meta:meta])*
@@ -1328,59 +9748,63 @@ macro_rules! wrapper {
             $(,)?
         }
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::borrow::Borrow<$inner_ty> for $name$(<$($lt),+>)? {
-            fn borrow(&self) -> &$inner_ty {
-                &self.$inner
+        $crate::__cfg_bytemuck! {
+            TransparentWrapper;
+
+            // SAFETY: the wrapper type is `repr(transparent)` over the inner type, and
+            // the other fields are ZSTs (checked by the compiler) bounded on `Zeroable`.
+            unsafe impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $crate::__bytemuck::TransparentWrapper<$inner_ty> for $name$(<$($lt),+>)?
+            where
+                $($field_ty: $crate::__bytemuck::Zeroable,)*
+            {
             }
         }
     };
-    // ================ Impl `Borrow` trait for the wrapper type. ================
+    // ================ Impl `bytemuck` traits for the wrapper type. ================
 
-    // ================ Impl `BorrowMut` trait for the wrapper type. ================
+    // ================ Check the `zerocopy` feature for the wrapper type, the derives are added in the `IMPL` pass. ================
     (
-        @INTERNAL WRAPPER_IMPL_BORROW_MUT <$target:ty>
-        $(#[$meta:meta])*
-        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+        @INTERNAL WRAPPER_IMPL_TRANSPARENT Zerocopy
+        $($tt:tt)*
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::borrow::BorrowMut<$target> for $name$(<$($lt),+>)? {
-            fn borrow_mut(&mut self) -> &mut $target {
-                &mut self.inner
-            }
+        $crate::__cfg_zerocopy! {
+            Zerocopy;
         }
     };
     (
-        @INTERNAL WRAPPER_IMPL_BORROW_MUT <$target:ty>
-        $(#[$meta:meta])*
-        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
-            $(#[$field_inner_meta:meta])*
-            $inner_vis:vis $inner:ident: $inner_ty:ty
-            $(
-                ,
-                $(#[$field_meta:meta])*
-                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
-            )*
-            $(,)?
-        }
+        @INTERNAL WRAPPER_IMPL_TRANSPARENT Unaligned
+        $($tt:tt)*
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::borrow::BorrowMut<$target> for $name$(<$($lt),+>)? {
-            fn borrow_mut(&mut self) -> &mut $target {
-                &mut self.$inner
-            }
+        $crate::__cfg_zerocopy! {
+            Unaligned;
         }
     };
+    // ================ Check the `zerocopy` feature for the wrapper type, the derives are added in the `IMPL` pass. ================
+
+    // ================ Impl `from_ref` and `from_mut` methods for the wrapper type. ================
     (
-        @INTERNAL WRAPPER_IMPL_BORROW_MUT
+        @INTERNAL WRAPPER_IMPL_TRANSPARENT RefCast
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::borrow::BorrowMut<$inner_ty> for $name$(<$($lt),+>)? {
-            fn borrow_mut(&mut self) -> &mut $inner_ty {
-                &mut self.inner
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
+            #[inline(always)]
+            #[doc = concat!("Converts a reference to the inner value into a reference to [`", stringify!($name), "`]")]
+            $inner_vis const fn from_ref<'__ref>(inner: &'__ref $inner_ty) -> &'__ref Self {
+                // SAFETY: the wrapper type is `repr(transparent)` over the inner type.
+                unsafe { &*(inner as *const $inner_ty as *const Self) }
+            }
+
+            #[inline(always)]
+            #[doc = concat!("Converts a mutable reference to the inner value into a mutable reference to [`", stringify!($name), "`]")]
+            $inner_vis fn from_mut<'__ref>(inner: &'__ref mut $inner_ty) -> &'__ref mut Self {
+                // SAFETY: the wrapper type is `repr(transparent)` over the inner type.
+                unsafe { &mut *(inner as *mut $inner_ty as *mut Self) }
             }
         }
     };
     (
-        @INTERNAL WRAPPER_IMPL_BORROW_MUT
+        @INTERNAL WRAPPER_IMPL_TRANSPARENT RefCast
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
             $(#[$field_inner_meta:meta])*
@@ -1388,36 +9812,57 @@ macro_rules! wrapper {
             $(
                 ,
                 $(#[$field_meta:meta])*
-                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+                $field_vis:vis $field:ident: $field_ty:ty = $field_default:expr
             )*
             $(,)?
         }
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::borrow::BorrowMut<$inner_ty> for $name$(<$($lt),+>)? {
-            fn borrow_mut(&mut self) -> &mut $inner_ty {
-                &mut self.$inner
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
+            #[inline(always)]
+            #[doc = concat!("Converts a reference to the inner value into a reference to [`", stringify!($name), "`]")]
+            $inner_vis const fn from_ref<'__ref>(inner: &'__ref $inner_ty) -> &'__ref Self {
+                // SAFETY: the wrapper type is `repr(transparent)` over the inner type, and
+                // the other fields are ZSTs (checked by the compiler) with default values.
+                unsafe { &*(inner as *const $inner_ty as *const Self) }
+            }
+
+            #[inline(always)]
+            #[doc = concat!("Converts a mutable reference to the inner value into a mutable reference to [`", stringify!($name), "`]")]
+            $inner_vis fn from_mut<'__ref>(inner: &'__ref mut $inner_ty) -> &'__ref mut Self {
+                // SAFETY: the wrapper type is `repr(transparent)` over the inner type, and
+                // the other fields are ZSTs (checked by the compiler) with default values.
+                unsafe { &mut *(inner as *mut $inner_ty as *mut Self) }
             }
         }
     };
-    // ================ Impl `Borrow` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_TRANSPARENT RefCast
+        $($tt:tt)*
+    ) => {
+        compile_error!(
+            "Invalid usage of `wrapper!` macro, cannot implement \
+            `from_ref` and `from_mut` for wrapper types with multiple \
+            fields but no default values given."
+        );
+    };
+    // ================ Impl `from_ref` and `from_mut` methods for the wrapper type. ================
 
-    // ================ Impl `Debug` trait for the wrapper type. ================
+    // ================ Impl `into_inner` method for the wrapper type. ================
     (
-        @INTERNAL WRAPPER_IMPL_DEBUG
+        @INTERNAL WRAPPER_IMPL_INTO_INNER
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::fmt::Debug for $name$(<$($lt),+>)?
-        where
-            $inner_ty: ::core::fmt::Debug,
-        {
-            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                self.inner.fmt(f)
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
+            #[inline(always)]
+            /// Consumes the wrapper and returns the inner value.
+            pub fn into_inner(self) -> $inner_ty {
+                self.inner
             }
         }
     };
     (
-        @INTERNAL WRAPPER_IMPL_DEBUG
+        @INTERNAL WRAPPER_IMPL_INTO_INNER
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
             $(#[$field_inner_meta:meta])*
@@ -1430,31 +9875,31 @@ macro_rules! wrapper {
             $(,)?
         }
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::fmt::Debug for $name$(<$($lt),+>)?
-        where
-            $inner_ty: ::core::fmt::Debug,
-        {
-            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                self.$inner.fmt(f)
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
+            #[inline(always)]
+            /// Consumes the wrapper and returns the inner value.
+            pub fn into_inner(self) -> $inner_ty {
+                self.$inner
             }
         }
     };
-    // ================ Impl `Debug` trait for the wrapper type. ================
+    // ================ Impl `into_inner` method for the wrapper type. ================
 
-    // ================ Impl `DebugName` trait for the wrapper type. ================
+    // ================ Impl `From` trait for the inner type. ================
     (
-        @INTERNAL WRAPPER_IMPL_DEBUG_NAME
+        @INTERNAL WRAPPER_IMPL_INTO
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::fmt::Debug for $name$(<$($lt),+>)? {
-            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                f.debug_struct(stringify!($name)).finish()
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::From<$name$(<$($lt),+>)?> for $inner_ty {
+            #[inline(always)]
+            fn from(wrapper: $name$(<$($lt),+>)?) -> Self {
+                wrapper.inner
             }
         }
     };
     (
-        @INTERNAL WRAPPER_IMPL_DEBUG_NAME
+        @INTERNAL WRAPPER_IMPL_INTO
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
             $(#[$field_inner_meta:meta])*
@@ -1467,31 +9912,30 @@ macro_rules! wrapper {
             $(,)?
         }
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::fmt::Debug for $name$(<$($lt),+>)? {
-            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                f.debug_struct(stringify!($name)).finish()
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::From<$name$(<$($lt),+>)?> for $inner_ty {
+            #[inline(always)]
+            fn from(wrapper: $name$(<$($lt),+>)?) -> Self {
+                wrapper.$inner
             }
         }
     };
-    // ================ Impl `DebugName` trait for the wrapper type. ================
+    // ================ Impl `From` trait for the inner type. ================
 
-    // ================ Impl `Display` trait for the wrapper type. ================
+    // ================ Impl binary operator traits for the wrapper type. ================
     (
-        @INTERNAL WRAPPER_IMPL_DISPLAY
+        @INTERNAL WRAPPER_IMPL_OPS_BINARY $trait:ident $method:ident
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::fmt::Display for $name$(<$($lt),+>)?
-        where
-            $inner_ty: ::core::fmt::Display,
-        {
-            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                self.inner.fmt(f)
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_BINARY $trait $method
+            $vis struct $name$(<$($lt$(:$clt$(+$dlt)*)?),+>)? {
+                $inner_vis inner: $inner_ty
             }
         }
     };
     (
-        @INTERNAL WRAPPER_IMPL_DISPLAY
+        @INTERNAL WRAPPER_IMPL_OPS_BINARY $trait:ident $method:ident
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
             $(#[$field_inner_meta:meta])*
@@ -1499,38 +9943,91 @@ macro_rules! wrapper {
             $(
                 ,
                 $(#[$field_meta:meta])*
-                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+                $field_vis:vis $field:ident: $field_ty:ty = $field_default:expr
             )*
             $(,)?
         }
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::fmt::Display for $name$(<$($lt),+>)?
+        // `Wrapper op Wrapper`
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::ops::$trait for $name$(<$($lt),+>)?
         where
-            $inner_ty: ::core::fmt::Display,
+            $inner_ty: ::core::ops::$trait<Output = $inner_ty>,
         {
-            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                self.$inner.fmt(f)
+            type Output = Self;
+
+            #[inline(always)]
+            fn $method(self, rhs: Self) -> Self::Output {
+                Self::const_from(::core::ops::$trait::$method(self.$inner, rhs.$inner))
             }
         }
-    };
-    // ================ Impl `Display` trait for the wrapper type. ================
 
-    // ================ Impl `Deref` trait for the wrapper type. ================
-    (
-        @INTERNAL WRAPPER_IMPL_DEREF <$target:ty>
-        $(#[$meta:meta])*
-        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
-    ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::ops::Deref for $name$(<$($lt),+>)? {
-            type Target = $target;
+        // `Wrapper op &Wrapper`
+        impl<'__rhs $(, $($lt$(:$clt$(+$dlt)*)?),+)?> ::core::ops::$trait<&'__rhs $name$(<$($lt),+>)?> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::ops::$trait<&'__rhs $inner_ty, Output = $inner_ty>,
+        {
+            type Output = Self;
 
-            fn deref(&self) -> &Self::Target {
-                &self.inner
+            #[inline(always)]
+            fn $method(self, rhs: &'__rhs $name$(<$($lt),+>)?) -> Self::Output {
+                Self::const_from(::core::ops::$trait::$method(self.$inner, &rhs.$inner))
+            }
+        }
+
+        // `&Wrapper op Wrapper`
+        impl<'__lhs $(, $($lt$(:$clt$(+$dlt)*)?),+)?> ::core::ops::$trait<$name$(<$($lt),+>)?> for &'__lhs $name$(<$($lt),+>)?
+        where
+            &'__lhs $inner_ty: ::core::ops::$trait<$inner_ty, Output = $inner_ty>,
+        {
+            type Output = $name$(<$($lt),+>)?;
+
+            #[inline(always)]
+            fn $method(self, rhs: $name$(<$($lt),+>)?) -> Self::Output {
+                $name::const_from(::core::ops::$trait::$method(&self.$inner, rhs.$inner))
+            }
+        }
+
+        // `&Wrapper op &Wrapper`
+        impl<'__lhs, '__rhs $(, $($lt$(:$clt$(+$dlt)*)?),+)?> ::core::ops::$trait<&'__rhs $name$(<$($lt),+>)?> for &'__lhs $name$(<$($lt),+>)?
+        where
+            &'__lhs $inner_ty: ::core::ops::$trait<&'__rhs $inner_ty, Output = $inner_ty>,
+        {
+            type Output = $name$(<$($lt),+>)?;
+
+            #[inline(always)]
+            fn $method(self, rhs: &'__rhs $name$(<$($lt),+>)?) -> Self::Output {
+                $name::const_from(::core::ops::$trait::$method(&self.$inner, &rhs.$inner))
+            }
+        }
+
+        // `Wrapper op Inner`
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::ops::$trait<$inner_ty> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::ops::$trait<Output = $inner_ty>,
+        {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $method(self, rhs: $inner_ty) -> Self::Output {
+                Self::const_from(::core::ops::$trait::$method(self.$inner, rhs))
+            }
+        }
+
+        // `&Wrapper op Inner`
+        impl<'__lhs $(, $($lt$(:$clt$(+$dlt)*)?),+)?> ::core::ops::$trait<$inner_ty> for &'__lhs $name$(<$($lt),+>)?
+        where
+            &'__lhs $inner_ty: ::core::ops::$trait<$inner_ty, Output = $inner_ty>,
+        {
+            type Output = $name$(<$($lt),+>)?;
+
+            #[inline(always)]
+            fn $method(self, rhs: $inner_ty) -> Self::Output {
+                $name::const_from(::core::ops::$trait::$method(&self.$inner, rhs))
             }
         }
     };
     (
-        @INTERNAL WRAPPER_IMPL_DEREF <$target:ty>
+        @INTERNAL WRAPPER_IMPL_OPS_BINARY $trait:ident $method:ident
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
             $(#[$field_inner_meta:meta])*
@@ -1538,34 +10035,34 @@ macro_rules! wrapper {
             $(
                 ,
                 $(#[$field_meta:meta])*
-                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+                $field_vis:vis $field:ident: $field_ty:ty
             )*
             $(,)?
         }
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::ops::Deref for $name$(<$($lt),+>)? {
-            type Target = $target;
-
-            fn deref(&self) -> &Self::Target {
-                &self.$inner
-            }
-        }
+        compile_error!(
+            "Invalid usage of `wrapper!` macro, cannot implement \
+            operator traits for wrapper types with multiple fields \
+            but no default values given."
+        );
     };
+    // ================ Impl binary operator traits for the wrapper type. ================
+
+    // ================ Impl compound assignment operator traits for the wrapper type. ================
     (
-        @INTERNAL WRAPPER_IMPL_DEREF
+        @INTERNAL WRAPPER_IMPL_OPS_ASSIGN $trait:ident $method:ident
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::ops::Deref for $name$(<$($lt),+>)? {
-            type Target = $inner_ty;
-
-            fn deref(&self) -> &Self::Target {
-                &self.inner
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_ASSIGN $trait $method
+            $vis struct $name$(<$($lt$(:$clt$(+$dlt)*)?),+>)? {
+                $inner_vis inner: $inner_ty
             }
         }
     };
     (
-        @INTERNAL WRAPPER_IMPL_DEREF
+        @INTERNAL WRAPPER_IMPL_OPS_ASSIGN $trait:ident $method:ident
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
             $(#[$field_inner_meta:meta])*
@@ -1578,30 +10075,56 @@ macro_rules! wrapper {
             $(,)?
         }
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::ops::Deref for $name$(<$($lt),+>)? {
-            type Target = $inner_ty;
+        // `Wrapper op= Wrapper`
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::ops::$trait for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::ops::$trait,
+        {
+            #[inline(always)]
+            fn $method(&mut self, rhs: Self) {
+                ::core::ops::$trait::$method(&mut self.$inner, rhs.$inner)
+            }
+        }
 
-            fn deref(&self) -> &Self::Target {
-                &self.$inner
+        // `Wrapper op= &Wrapper`
+        impl<'__rhs $(, $($lt$(:$clt$(+$dlt)*)?),+)?> ::core::ops::$trait<&'__rhs $name$(<$($lt),+>)?> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::ops::$trait<&'__rhs $inner_ty>,
+        {
+            #[inline(always)]
+            fn $method(&mut self, rhs: &'__rhs $name$(<$($lt),+>)?) {
+                ::core::ops::$trait::$method(&mut self.$inner, &rhs.$inner)
+            }
+        }
+
+        // `Wrapper op= Inner`
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::ops::$trait<$inner_ty> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::ops::$trait,
+        {
+            #[inline(always)]
+            fn $method(&mut self, rhs: $inner_ty) {
+                ::core::ops::$trait::$method(&mut self.$inner, rhs)
             }
         }
     };
-    // ================ Impl `Deref` trait for the wrapper type. ================
+    // ================ Impl compound assignment operator traits for the wrapper type. ================
 
-    // ================ Impl `DerefMut` traits for the wrapper type. ================
+    // ================ Impl unary operator traits for the wrapper type. ================
     (
-        @INTERNAL WRAPPER_IMPL_DEREF_MUT <$target:ty>
+        @INTERNAL WRAPPER_IMPL_OPS_UNARY $trait:ident $method:ident
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::ops::DerefMut for $name$(<$($lt),+>)? {
-            fn deref_mut(&mut self) -> &mut Self::Target {
-                &mut self.inner
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_UNARY $trait $method
+            $vis struct $name$(<$($lt$(:$clt$(+$dlt)*)?),+>)? {
+                $inner_vis inner: $inner_ty
             }
         }
     };
     (
-        @INTERNAL WRAPPER_IMPL_DEREF_MUT <$target:ty>
+        @INTERNAL WRAPPER_IMPL_OPS_UNARY $trait:ident $method:ident
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
             $(#[$field_inner_meta:meta])*
@@ -1609,30 +10132,39 @@ macro_rules! wrapper {
             $(
                 ,
                 $(#[$field_meta:meta])*
-                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+                $field_vis:vis $field:ident: $field_ty:ty = $field_default:expr
             )*
             $(,)?
         }
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::ops::DerefMut for $name$(<$($lt),+>)? {
-            fn deref_mut(&mut self) -> &mut Self::Target {
-                &mut self.$inner
+        // `op Wrapper`
+        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::ops::$trait for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::ops::$trait<Output = $inner_ty>,
+        {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $method(self) -> Self::Output {
+                Self::const_from(::core::ops::$trait::$method(self.$inner))
             }
-        }
-    };
-    (
-        @INTERNAL WRAPPER_IMPL_DEREF_MUT
-        $(#[$meta:meta])*
-        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
-    ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::ops::DerefMut for $name$(<$($lt),+>)? {
-            fn deref_mut(&mut self) -> &mut Self::Target {
-                &mut self.inner
+        }
+
+        // `op &Wrapper`
+        impl<'__lhs $(, $($lt$(:$clt$(+$dlt)*)?),+)?> ::core::ops::$trait for &'__lhs $name$(<$($lt),+>)?
+        where
+            &'__lhs $inner_ty: ::core::ops::$trait<Output = $inner_ty>,
+        {
+            type Output = $name$(<$($lt),+>)?;
+
+            #[inline(always)]
+            fn $method(self) -> Self::Output {
+                $name::const_from(::core::ops::$trait::$method(&self.$inner))
             }
         }
     };
     (
-        @INTERNAL WRAPPER_IMPL_DEREF_MUT
+        @INTERNAL WRAPPER_IMPL_OPS_UNARY $trait:ident $method:ident
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
             $(#[$field_inner_meta:meta])*
@@ -1640,42 +10172,34 @@ macro_rules! wrapper {
             $(
                 ,
                 $(#[$field_meta:meta])*
-                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+                $field_vis:vis $field:ident: $field_ty:ty
             )*
             $(,)?
         }
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::ops::DerefMut for $name$(<$($lt),+>)? {
-            fn deref_mut(&mut self) -> &mut Self::Target {
-                &mut self.$inner
-            }
-        }
+        compile_error!(
+            "Invalid usage of `wrapper!` macro, cannot implement \
+            operator traits for wrapper types with multiple fields \
+            but no default values given."
+        );
     };
-    // ================ Impl `DerefMut` traits for the wrapper type. ================
+    // ================ Impl unary operator traits for the wrapper type. ================
 
-    // ================ Impl `From` trait for the wrapper type. ================
+    // ================ Impl shift operator traits for the wrapper type. ================
     (
-        @INTERNAL WRAPPER_IMPL_FROM
+        @INTERNAL WRAPPER_IMPL_OPS_SHIFT $trait:ident $method:ident
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::From<$inner_ty> for $name$(<$($lt),+>)? {
-            fn from(inner: $inner_ty) -> Self {
-                Self::const_from(inner)
-            }
-        }
-
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
-            /// Creates a new instance of the wrapper type from the inner value.
-            #[allow(unreachable_pub)]
-            #[inline(always)]
-            pub const fn from(inner: $inner_ty) -> Self {
-                Self::const_from(inner)
+        $crate::wrapper! {
+            @INTERNAL WRAPPER_IMPL_OPS_SHIFT $trait $method
+            $vis struct $name$(<$($lt$(:$clt$(+$dlt)*)?),+>)? {
+                $inner_vis inner: $inner_ty
             }
         }
     };
     (
-        @INTERNAL WRAPPER_IMPL_FROM
+        @INTERNAL WRAPPER_IMPL_OPS_SHIFT $trait:ident $method:ident
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
             $(#[$field_inner_meta:meta])*
@@ -1688,23 +10212,34 @@ macro_rules! wrapper {
             $(,)?
         }
     ) => {
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? ::core::convert::From<$inner_ty> for $name$(<$($lt),+>)? {
-            fn from($inner: $inner_ty) -> Self {
-                Self::const_from($inner)
+        // `Wrapper op Rhs`
+        impl<$($($lt$(:$clt$(+$dlt)*)?,)+)? __Rhs> ::core::ops::$trait<__Rhs> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::ops::$trait<__Rhs, Output = $inner_ty>,
+        {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $method(self, rhs: __Rhs) -> Self::Output {
+                Self::const_from(::core::ops::$trait::$method(self.$inner, rhs))
             }
         }
 
-        impl$(<$($lt$(:$clt$(+$dlt)*)?),+>)? $name$(<$($lt),+>)? {
-            /// Creates a new instance of the wrapper type from the inner value.
-            #[allow(unreachable_pub)]
+        // `&Wrapper op Rhs`
+        impl<'__lhs, $($($lt$(:$clt$(+$dlt)*)?,)+)? __Rhs> ::core::ops::$trait<__Rhs> for &'__lhs $name$(<$($lt),+>)?
+        where
+            &'__lhs $inner_ty: ::core::ops::$trait<__Rhs, Output = $inner_ty>,
+        {
+            type Output = $name$(<$($lt),+>)?;
+
             #[inline(always)]
-            pub const fn from($inner: $inner_ty) -> Self {
-                Self::const_from($inner)
+            fn $method(self, rhs: __Rhs) -> Self::Output {
+                $name::const_from(::core::ops::$trait::$method(&self.$inner, rhs))
             }
         }
     };
     (
-        @INTERNAL WRAPPER_IMPL_FROM
+        @INTERNAL WRAPPER_IMPL_OPS_SHIFT $trait:ident $method:ident
         $(#[$meta:meta])*
         $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
             $(#[$field_inner_meta:meta])*
@@ -1719,11 +10254,50 @@ macro_rules! wrapper {
     ) => {
         compile_error!(
             "Invalid usage of `wrapper!` macro, cannot implement \
-            `From` trait for wrapper types with multiple fields\
+            operator traits for wrapper types with multiple fields \
             but no default values given."
         );
     };
-    // ================ Impl `From` trait for the wrapper type. ================
+    (
+        @INTERNAL WRAPPER_IMPL_OPS_SHIFT_ASSIGN $trait:ident $method:ident
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? ($inner_vis:vis $inner_ty:ty);
+    ) => {
+        impl<$($($lt$(:$clt$(+$dlt)*)?,)+)? __Rhs> ::core::ops::$trait<__Rhs> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::ops::$trait<__Rhs>,
+        {
+            #[inline(always)]
+            fn $method(&mut self, rhs: __Rhs) {
+                ::core::ops::$trait::$method(&mut self.inner, rhs)
+            }
+        }
+    };
+    (
+        @INTERNAL WRAPPER_IMPL_OPS_SHIFT_ASSIGN $trait:ident $method:ident
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident$(<$($lt:tt$(:$clt:tt$(+$dlt:tt)*)?),+>)? {
+            $(#[$field_inner_meta:meta])*
+            $inner_vis:vis $inner:ident: $inner_ty:ty
+            $(
+                ,
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty$( = $field_default: expr)?
+            )*
+            $(,)?
+        }
+    ) => {
+        impl<$($($lt$(:$clt$(+$dlt)*)?,)+)? __Rhs> ::core::ops::$trait<__Rhs> for $name$(<$($lt),+>)?
+        where
+            $inner_ty: ::core::ops::$trait<__Rhs>,
+        {
+            #[inline(always)]
+            fn $method(&mut self, rhs: __Rhs) {
+                ::core::ops::$trait::$method(&mut self.$inner, rhs)
+            }
+        }
+    };
+    // ================ Impl shift operator traits for the wrapper type. ================
 
     // No other wrapper_impl meta
     (@INTERNAL WRAPPER_IMPL $($tt:tt)*) => {};
@@ -1739,6 +10313,417 @@ macro_rules! wrapper {
     // Core macro for the wrapper type.
     ($($tt:tt)*) => {
         $crate::wrapper!(@INTERNAL IMPL $($tt)*);
-        $crate::wrapper!(@INTERNAL WRAPPER_IMPL $($tt)*);
+        $crate::wrapper!(@INTERNAL IMPL_CONST_FROM $($tt)*);
+        $crate::wrapper!(@INTERNAL IMPL_PIN_PROJECT $($tt)*);
+        $crate::wrapper!(@INTERNAL WRAPPER_IMPL_VALIDATED [] $($tt)*);
+    };
+}
+
+/// Wrapper types with `TryFrom` reject the attributes exposing the inner value
+/// mutably, see the `TryFrom` section of [`wrapper!`].
+///
+/// ```rust
+/// # fn is_valid(inner: &str) -> bool { !inner.is_empty() }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(AsRef)]
+///     #[wrapper_impl(Deref)]
+///     #[wrapper_impl(TryFrom(validate = is_valid))]
+///     pub struct ExampleWrapper(String);
+/// );
+/// ```
+///
+/// ```rust,compile_fail
+/// # fn is_valid(inner: &str) -> bool { !inner.is_empty() }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(AsMut)]
+///     #[wrapper_impl(TryFrom(validate = is_valid))]
+///     pub struct ExampleWrapper(String);
+/// );
+/// ```
+///
+/// ```rust,compile_fail
+/// # fn is_valid(inner: &str) -> bool { !inner.is_empty() }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(AsMut<str>)]
+///     #[wrapper_impl(TryFrom(validate = is_valid))]
+///     pub struct ExampleWrapper(String);
+/// );
+/// ```
+///
+/// ```rust,compile_fail
+/// # fn is_valid(inner: &str) -> bool { !inner.is_empty() }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(ConstAsMut)]
+///     #[wrapper_impl(TryFrom(validate = is_valid))]
+///     pub struct ExampleWrapper(String);
+/// );
+/// ```
+///
+/// ```rust,compile_fail
+/// # fn is_valid(inner: &str) -> bool { !inner.is_empty() }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(BorrowMut)]
+///     #[wrapper_impl(TryFrom(validate = is_valid))]
+///     pub struct ExampleWrapper(String);
+/// );
+/// ```
+///
+/// ```rust,compile_fail
+/// # fn is_valid(inner: &str) -> bool { !inner.is_empty() }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(DerefMut)]
+///     #[wrapper_impl(TryFrom(validate = is_valid))]
+///     pub struct ExampleWrapper(String);
+/// );
+/// ```
+///
+/// ```rust,compile_fail
+/// # fn is_valid(inner: &[u8]) -> bool { !inner.is_empty() }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(IndexMut)]
+///     #[wrapper_impl(TryFrom(validate = is_valid))]
+///     pub struct ExampleWrapper(Vec<u8>);
+/// );
+/// ```
+///
+/// ```rust,compile_fail
+/// # fn is_valid(inner: &str) -> bool { !inner.is_empty() }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Extend)]
+///     #[wrapper_impl(TryFrom(validate = is_valid))]
+///     pub struct ExampleWrapper(String);
+/// );
+/// ```
+///
+/// ```rust,compile_fail
+/// # fn is_valid(inner: &[u8]) -> bool { !inner.is_empty() }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(IntoIterator)]
+///     #[wrapper_impl(TryFrom(validate = is_valid))]
+///     pub struct ExampleWrapper(Vec<u8>);
+/// );
+/// ```
+///
+/// ```rust,compile_fail
+/// # fn is_valid(inner: &core::ops::Range<u8>) -> bool { !inner.is_empty() }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Iterator)]
+///     #[wrapper_impl(TryFrom(validate = is_valid))]
+///     pub struct ExampleWrapper(core::ops::Range<u8>);
+/// );
+/// ```
+///
+/// ```rust,compile_fail
+/// # fn is_valid(inner: &core::ops::Range<u8>) -> bool { !inner.is_empty() }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(DoubleEndedIterator)]
+///     #[wrapper_impl(TryFrom(validate = is_valid))]
+///     pub struct ExampleWrapper(core::ops::Range<u8>);
+/// );
+/// ```
+///
+/// ```rust,compile_fail
+/// # fn is_valid(inner: &str) -> bool { !inner.is_empty() }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(FmtWrite)]
+///     #[wrapper_impl(TryFrom(validate = is_valid))]
+///     pub struct ExampleWrapper(String);
+/// );
+/// ```
+///
+/// ```rust,compile_fail
+/// # fn is_valid(_: &std::collections::hash_map::DefaultHasher) -> bool { true }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Hasher)]
+///     #[wrapper_impl(TryFrom(validate = is_valid))]
+///     pub struct ExampleWrapper(std::collections::hash_map::DefaultHasher);
+/// );
+/// ```
+///
+/// ```rust,compile_fail
+/// # fn is_valid(_: &core::future::Ready<u8>) -> bool { true }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Future)]
+///     #[wrapper_impl(TryFrom(validate = is_valid))]
+///     pub struct ExampleWrapper(core::future::Ready<u8>);
+/// );
+/// ```
+///
+/// ```rust,compile_fail
+/// # fn is_valid(inner: &str) -> bool { !inner.is_empty() }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(PinProject)]
+///     #[wrapper_impl(TryFrom(validate = is_valid))]
+///     pub struct ExampleWrapper(String);
+/// );
+/// ```
+#[cfg(doctest)]
+pub struct TryFromConflicts;
+
+/// Like [`TryFromConflicts`], for the attributes requiring the `std` feature.
+///
+/// ```rust,compile_fail
+/// # fn is_valid(inner: &std::io::Cursor<Vec<u8>>) -> bool { !inner.get_ref().is_empty() }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Read)]
+///     #[wrapper_impl(TryFrom(validate = is_valid))]
+///     pub struct ExampleWrapper(std::io::Cursor<Vec<u8>>);
+/// );
+/// ```
+///
+/// ```rust,compile_fail
+/// # fn is_valid(inner: &std::io::Cursor<Vec<u8>>) -> bool { !inner.get_ref().is_empty() }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Write)]
+///     #[wrapper_impl(TryFrom(validate = is_valid))]
+///     pub struct ExampleWrapper(std::io::Cursor<Vec<u8>>);
+/// );
+/// ```
+///
+/// ```rust,compile_fail
+/// # fn is_valid(inner: &std::io::Cursor<Vec<u8>>) -> bool { !inner.get_ref().is_empty() }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Seek)]
+///     #[wrapper_impl(TryFrom(validate = is_valid))]
+///     pub struct ExampleWrapper(std::io::Cursor<Vec<u8>>);
+/// );
+/// ```
+///
+/// ```rust,compile_fail
+/// # fn is_valid(inner: &std::io::Cursor<Vec<u8>>) -> bool { !inner.get_ref().is_empty() }
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(BufRead)]
+///     #[wrapper_impl(TryFrom(validate = is_valid))]
+///     pub struct ExampleWrapper(std::io::Cursor<Vec<u8>>);
+/// );
+/// ```
+#[cfg(all(doctest, feature = "std"))]
+pub struct TryFromConflictsStd;
+
+/// `Deserialize` requires default values for the other fields, see the
+/// `Serialize` and `Deserialize` section of [`wrapper!`].
+///
+/// ```rust,compile_fail
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Deserialize)]
+///     pub struct ExampleWrapperId<'a> {
+///         inner: u64,
+///         _a: core::marker::PhantomData<&'a ()>,
+///     }
+/// );
+/// ```
+#[cfg(all(doctest, feature = "serde"))]
+pub struct DeserializeWithoutDefaults;
+
+/// The `bytemuck` traits require the other fields to be ZSTs, see the
+/// `Zeroable`, `Pod` and `TransparentWrapper` section of [`wrapper!`].
+///
+/// ```rust,compile_fail
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Zeroable)]
+///     pub struct ExampleWrapperPort {
+///         inner: u16,
+///         flags: u8 = 0,
+///     }
+/// );
+/// ```
+#[cfg(all(doctest, feature = "bytemuck"))]
+pub struct BytemuckNonZstFields;
+
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+/// Emits the given items, which require the `std` feature.
+macro_rules! __cfg_std {
+    ($trait:ident; $($tt:tt)*) => {
+        $($tt)*
+    };
+}
+
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+/// Emits the given items, which require the `std` feature.
+macro_rules! __cfg_std {
+    ($trait:ident; $($tt:tt)*) => {
+        compile_error!(::core::concat!(
+            "Invalid usage of `wrapper!` macro, implementing `",
+            ::core::stringify!($trait),
+            "` trait requires the `std` feature of `wrapper-lite`."
+        ));
+    };
+}
+
+#[cfg(feature = "tokio")]
+#[doc(hidden)]
+#[macro_export]
+/// Emits the given items, which require the `tokio` feature.
+macro_rules! __cfg_tokio {
+    ($trait:ident; $($tt:tt)*) => {
+        $($tt)*
+    };
+}
+
+#[cfg(not(feature = "tokio"))]
+#[doc(hidden)]
+#[macro_export]
+/// Emits the given items, which require the `tokio` feature.
+macro_rules! __cfg_tokio {
+    ($trait:ident; $($tt:tt)*) => {
+        compile_error!(::core::concat!(
+            "Invalid usage of `wrapper!` macro, implementing `",
+            ::core::stringify!($trait),
+            "` trait requires the `tokio` feature of `wrapper-lite`."
+        ));
+    };
+}
+
+#[cfg(feature = "futures-io")]
+#[doc(hidden)]
+#[macro_export]
+/// Emits the given items, which require the `futures-io` feature.
+macro_rules! __cfg_futures_io {
+    ($trait:ident; $($tt:tt)*) => {
+        $($tt)*
+    };
+}
+
+#[cfg(not(feature = "futures-io"))]
+#[doc(hidden)]
+#[macro_export]
+/// Emits the given items, which require the `futures-io` feature.
+macro_rules! __cfg_futures_io {
+    ($trait:ident; $($tt:tt)*) => {
+        compile_error!(::core::concat!(
+            "Invalid usage of `wrapper!` macro, implementing `",
+            ::core::stringify!($trait),
+            "` trait requires the `futures-io` feature of `wrapper-lite`."
+        ));
+    };
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+/// Emits the given items, which require the `serde` feature.
+macro_rules! __cfg_serde {
+    ($trait:ident; $($tt:tt)*) => {
+        $($tt)*
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+/// Emits the given items, which require the `serde` feature.
+///
+/// ```rust,compile_fail
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Serialize)]
+///     pub struct ExampleWrapperId(u64);
+/// );
+/// ```
+macro_rules! __cfg_serde {
+    ($trait:ident; $($tt:tt)*) => {
+        compile_error!(::core::concat!(
+            "Invalid usage of `wrapper!` macro, implementing `",
+            ::core::stringify!($trait),
+            "` trait requires the `serde` feature of `wrapper-lite`."
+        ));
+    };
+}
+
+#[cfg(feature = "bytemuck")]
+#[doc(hidden)]
+#[macro_export]
+/// Emits the given items, which require the `bytemuck` feature.
+macro_rules! __cfg_bytemuck {
+    ($trait:ident; $($tt:tt)*) => {
+        $($tt)*
+    };
+}
+
+#[cfg(not(feature = "bytemuck"))]
+#[doc(hidden)]
+#[macro_export]
+/// Emits the given items, which require the `bytemuck` feature.
+///
+/// ```rust,compile_fail
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Pod)]
+///     #[derive(Clone, Copy)]
+///     pub struct ExampleWrapperPort(u16);
+/// );
+/// ```
+macro_rules! __cfg_bytemuck {
+    ($trait:ident; $($tt:tt)*) => {
+        compile_error!(::core::concat!(
+            "Invalid usage of `wrapper!` macro, implementing `",
+            ::core::stringify!($trait),
+            "` trait requires the `bytemuck` feature of `wrapper-lite`."
+        ));
+    };
+}
+
+#[cfg(feature = "zerocopy")]
+#[doc(hidden)]
+#[macro_export]
+/// Emits the given items, which require the `zerocopy` feature.
+macro_rules! __cfg_zerocopy {
+    ($trait:ident; $($tt:tt)*) => {
+        $($tt)*
+    };
+}
+
+#[cfg(not(feature = "zerocopy"))]
+#[doc(hidden)]
+#[macro_export]
+/// Emits the given items, which require the `zerocopy` feature.
+///
+/// ```rust,compile_fail
+/// wrapper_lite::wrapper!(
+///     #[wrapper_impl(Zerocopy)]
+///     pub struct ExampleWrapperPort(u16);
+/// );
+/// ```
+macro_rules! __cfg_zerocopy {
+    ($trait:ident; $($tt:tt)*) => {
+        compile_error!(::core::concat!(
+            "Invalid usage of `wrapper!` macro, implementing `",
+            ::core::stringify!($trait),
+            "` trait requires the `zerocopy` feature of `wrapper-lite`."
+        ));
+    };
+}
+
+#[cfg(feature = "zerocopy")]
+#[doc(hidden)]
+#[macro_export]
+/// Adds the given `zerocopy` derives to the wrapper type.
+macro_rules! __cfg_zerocopy_derive {
+    ([$($wrapper_impl:tt)*] [$($derive:ident),+] $($tt:tt)*) => {
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($wrapper_impl)*
+            // The derives cannot refer to `zerocopy` through `$crate`, see the
+            // `Zerocopy` docs.
+            #[derive($(::zerocopy::$derive),+)]
+            $($tt)*
+        }
+    };
+}
+
+#[cfg(not(feature = "zerocopy"))]
+#[doc(hidden)]
+#[macro_export]
+/// Adds the given `zerocopy` derives to the wrapper type.
+macro_rules! __cfg_zerocopy_derive {
+    ([$($wrapper_impl:tt)*] [$($derive:ident),+] $($tt:tt)*) => {
+        // The missing feature is reported in `WRAPPER_IMPL`.
+        $crate::wrapper! {
+            @INTERNAL IMPL
+            $($wrapper_impl)*
+            $($tt)*
+        }
     };
 }
@@ -0,0 +1,128 @@
+#![cfg(any(feature = "tokio", feature = "futures-io"))]
+#![allow(unused)]
+#![allow(unreachable_pub)]
+#![allow(dead_code)]
+#![allow(non_snake_case)]
+
+use wrapper_lite::*;
+
+// === TokioAsyncRead, TokioAsyncWrite, TokioAsyncBufRead, TokioAsyncSeek ===
+
+#[cfg(feature = "tokio")]
+mod tokio_io {
+    use std::io::{Cursor, SeekFrom};
+
+    use tokio::io::{
+        duplex, AsyncBufReadExt, AsyncReadExt, AsyncSeekExt, AsyncWriteExt, BufReader, DuplexStream,
+    };
+    use wrapper_lite::*;
+
+    wrapper!(
+        #[wrapper_impl(TokioAsyncRead)]
+        #[wrapper_impl(TokioAsyncWrite)]
+        pub struct TestWrapperTokioStream(DuplexStream);
+    );
+
+    wrapper!(
+        #[wrapper_impl(TokioAsyncRead)]
+        #[wrapper_impl(TokioAsyncBufRead)]
+        pub struct TestWrapperTokioBufRead<R>(BufReader<R>);
+    );
+
+    wrapper!(
+        #[wrapper_impl(TokioAsyncRead)]
+        #[wrapper_impl(TokioAsyncWrite)]
+        #[wrapper_impl(TokioAsyncSeek)]
+        pub struct TestComplexWrapperTokioSeek<'a> {
+            inner_can_be_any_name: Cursor<Vec<u8>>,
+            _a: ::core::marker::PhantomData<&'a ()>,
+        }
+    );
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_impl_TokioAsyncIo() {
+        let (client, server) = duplex(64);
+        let mut client = TestWrapperTokioStream::const_from(client);
+        let server = TestWrapperTokioStream::const_from(server);
+
+        client.write_all(b"hello\nworld").await.unwrap();
+        client.shutdown().await.unwrap();
+
+        let mut server = TestWrapperTokioBufRead::const_from(BufReader::new(server));
+
+        let mut line = String::new();
+        server.read_line(&mut line).await.unwrap();
+        assert_eq!(line, "hello\n");
+
+        let mut rest = String::new();
+        server.read_to_string(&mut rest).await.unwrap();
+        assert_eq!(rest, "world");
+
+        let mut cursor = TestComplexWrapperTokioSeek {
+            inner_can_be_any_name: Cursor::new(Vec::new()),
+            _a: ::core::marker::PhantomData,
+        };
+        cursor.write_all(b"abc").await.unwrap();
+        assert_eq!(cursor.seek(SeekFrom::Start(1)).await.unwrap(), 1);
+
+        let mut buf = [0u8; 2];
+        cursor.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"bc");
+    }
+}
+
+// === TokioAsyncRead, TokioAsyncWrite, TokioAsyncBufRead, TokioAsyncSeek ===
+
+// === FuturesAsyncRead, FuturesAsyncWrite, FuturesAsyncBufRead, FuturesAsyncSeek ===
+
+#[cfg(feature = "futures-io")]
+mod futures_io {
+    use std::io::SeekFrom;
+
+    use futures_util::io::{AsyncBufReadExt, AsyncReadExt, AsyncSeekExt, AsyncWriteExt, Cursor};
+    use wrapper_lite::*;
+
+    wrapper!(
+        #[wrapper_impl(FuturesAsyncRead)]
+        #[wrapper_impl(FuturesAsyncWrite)]
+        #[wrapper_impl(FuturesAsyncBufRead)]
+        #[wrapper_impl(FuturesAsyncSeek)]
+        pub struct TestWrapperFuturesIo(Cursor<Vec<u8>>);
+    );
+
+    wrapper!(
+        #[wrapper_impl(FuturesAsyncWrite)]
+        #[wrapper_impl(PinProject)]
+        pub struct TestComplexWrapperFuturesIo<W> {
+            inner_can_be_any_name: W,
+            written: usize,
+        }
+    );
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_impl_FuturesAsyncIo() {
+        let mut a = TestWrapperFuturesIo::const_from(Cursor::new(Vec::new()));
+
+        a.write_all(b"hello\nworld").await.unwrap();
+        a.flush().await.unwrap();
+        assert_eq!(a.seek(SeekFrom::Start(0)).await.unwrap(), 0);
+
+        let mut line = String::new();
+        a.read_line(&mut line).await.unwrap();
+        assert_eq!(line, "hello\n");
+
+        let mut rest = String::new();
+        a.read_to_string(&mut rest).await.unwrap();
+        assert_eq!(rest, "world");
+
+        let mut b = TestComplexWrapperFuturesIo {
+            inner_can_be_any_name: Cursor::new(Vec::new()),
+            written: 0,
+        };
+        b.write_all(b"data").await.unwrap();
+        b.close().await.unwrap();
+        assert_eq!(b.inner_can_be_any_name.into_inner(), b"data");
+    }
+}
+
+// === FuturesAsyncRead, FuturesAsyncWrite, FuturesAsyncBufRead, FuturesAsyncSeek ===
@@ -0,0 +1,110 @@
+#![cfg(feature = "bytemuck")]
+#![allow(unused)]
+#![allow(unreachable_pub)]
+#![allow(dead_code)]
+#![allow(non_snake_case)]
+
+use bytemuck::{Pod, TransparentWrapper, Zeroable};
+use wrapper_lite::*;
+
+// === Zeroable, Pod, TransparentWrapper ===
+
+wrapper!(
+    #[wrapper_impl(Pod)]
+    #[wrapper_impl(TransparentWrapper)]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct TestWrapperPod(u32);
+);
+
+wrapper!(
+    #[wrapper_impl(Zeroable)]
+    #[wrapper_impl(TransparentWrapper)]
+    #[derive(Debug, PartialEq)]
+    pub struct TestWrapperZeroable(Option<core::num::NonZeroU32>);
+);
+
+wrapper!(
+    #[wrapper_impl(Pod)]
+    #[wrapper_impl(TransparentWrapper)]
+    #[derive(Clone, Copy)]
+    pub struct TestWrapperPodGeneric<T>(T);
+);
+
+wrapper!(
+    #[wrapper_impl(Pod)]
+    #[wrapper_impl(TransparentWrapper)]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct TestComplexWrapperPod<M> {
+        inner_can_be_any_name: u32,
+        _marker: ::core::marker::PhantomData<M> = ::core::marker::PhantomData,
+    }
+);
+
+wrapper!(
+    #[wrapper_impl(Zeroable)]
+    #[wrapper_impl(TransparentWrapper)]
+    #[derive(Debug, PartialEq)]
+    #[repr(transparent)]
+    pub struct TestComplexWrapperZeroable<'a> {
+        inner_can_be_any_name: Option<&'a u32>,
+        _a: ::core::marker::PhantomData<&'a ()>,
+    }
+);
+
+#[test]
+fn test_impl_bytemuck() {
+    let words: &[TestWrapperPod] = bytemuck::cast_slice(&[1u32, 2, 3]);
+    assert_eq!(words[2], TestWrapperPod::const_from(3));
+
+    let max = TestWrapperPod::const_from(u32::MAX);
+    assert_eq!(bytemuck::bytes_of(&max), [0xff; 4]);
+
+    assert_eq!(TestWrapperPod::zeroed(), TestWrapperPod::const_from(0));
+    assert_eq!(
+        TestWrapperZeroable::zeroed(),
+        TestWrapperZeroable::const_from(None)
+    );
+
+    let mut value = 1u32;
+    *TestWrapperPod::wrap_mut(&mut value) = TestWrapperPod::const_from(2);
+    assert_eq!(value, 2);
+    assert_eq!(TestWrapperPod::peel(TestWrapperPod::const_from(3)), 3);
+
+    let generic: [TestWrapperPodGeneric<u8>; 2] = bytemuck::cast([1u8, 2]);
+    assert_eq!(generic[1].inner, 2);
+
+    _assert_impl_pod::<TestWrapperPodGeneric<[u16; 4]>>();
+}
+
+#[test]
+fn test_impl_bytemuck_complex() {
+    let words: &[TestComplexWrapperPod<()>] = bytemuck::cast_slice(&[1u32, 2]);
+    assert_eq!(words[1], TestComplexWrapperPod::const_from(2));
+    assert_eq!(
+        TestComplexWrapperPod::<()>::zeroed(),
+        TestComplexWrapperPod::const_from(0)
+    );
+    assert_eq!(
+        TestComplexWrapperPod::<()>::wrap_ref(&3).inner_can_be_any_name,
+        3
+    );
+
+    assert_eq!(
+        TestComplexWrapperZeroable::zeroed().inner_can_be_any_name,
+        None
+    );
+    assert_eq!(
+        TestComplexWrapperZeroable::wrap_ref(&Some(&1)).inner_can_be_any_name,
+        Some(&1)
+    );
+}
+
+// === Zeroable, Pod, TransparentWrapper ===
+
+// === utilities ===
+
+fn _assert_impl_pod<T>()
+where
+    T: Pod,
+{
+}
@@ -0,0 +1,67 @@
+#![allow(unused)]
+#![allow(unreachable_pub)]
+#![allow(dead_code)]
+#![allow(non_snake_case)]
+
+use wrapper_lite::*;
+
+// === Clone, Copy ===
+
+struct NotClone;
+
+wrapper!(
+    #[wrapper_impl(AsRef)]
+    #[wrapper_impl(Clone)]
+    #[wrapper_impl(Copy)]
+    pub struct TestWrapperCloneRef<'a, P>(&'a P);
+);
+
+wrapper!(
+    #[wrapper_impl(Clone)]
+    pub struct TestWrapperClone(String);
+);
+
+wrapper!(
+    #[wrapper_impl(Clone)]
+    #[wrapper_impl(Copy)]
+    pub struct TestComplexWrapperClone<'a, M> {
+        inner_can_be_any_name: u32,
+        _a: ::core::marker::PhantomData<&'a ()>,
+        _marker: ::core::marker::PhantomData<M>,
+        extra: u8,
+    }
+);
+
+#[test]
+fn test_impl_Clone() {
+    let data = NotClone;
+    let wrapper = TestWrapperCloneRef::const_from(&data);
+    let copied = wrapper;
+    assert!(core::ptr::eq(*wrapper.as_inner(), *copied.as_inner()));
+
+    let wrapper = TestWrapperClone::const_from(String::from("Hello"));
+    assert_eq!(wrapper.clone().inner, "Hello");
+
+    let wrapper = TestComplexWrapperClone::<'_, NotClone> {
+        inner_can_be_any_name: 42,
+        _a: ::core::marker::PhantomData,
+        _marker: ::core::marker::PhantomData,
+        extra: 1,
+    };
+    let copied = wrapper;
+    assert_eq!(wrapper.inner_can_be_any_name, copied.inner_can_be_any_name);
+    assert_eq!(wrapper.extra, copied.extra);
+
+    _assert_impl_copy::<TestWrapperCloneRef<'_, NotClone>>();
+    _assert_impl_copy::<TestComplexWrapperClone<'_, NotClone>>();
+}
+
+// === Clone, Copy ===
+
+// === utilities ===
+
+fn _assert_impl_copy<T>()
+where
+    T: ::core::marker::Copy,
+{
+}
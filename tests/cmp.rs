@@ -0,0 +1,333 @@
+#![allow(unused)]
+#![allow(unreachable_pub)]
+#![allow(dead_code)]
+#![allow(non_snake_case)]
+
+use core::cmp::Ordering;
+use core::hash::{BuildHasher, Hash, Hasher};
+use std::collections::hash_map::DefaultHasher;
+
+use wrapper_lite::*;
+
+// === PartialEq, Eq, PartialOrd, Ord, Hash ===
+
+wrapper!(
+    #[wrapper_impl(PartialEq)]
+    #[wrapper_impl(Eq)]
+    #[wrapper_impl(PartialOrd)]
+    #[wrapper_impl(Ord)]
+    #[wrapper_impl(Hash)]
+    pub struct TestWrapperCmp(String);
+);
+
+struct NotComparable;
+
+wrapper!(
+    #[wrapper_impl(PartialEq)]
+    #[wrapper_impl(Eq)]
+    #[wrapper_impl(PartialOrd)]
+    #[wrapper_impl(Ord)]
+    #[wrapper_impl(Hash)]
+    pub struct TestComplexWrapperCmp<'a, M> {
+        inner_can_be_any_name: u32,
+        _a: ::core::marker::PhantomData<&'a ()> = ::core::marker::PhantomData,
+        _marker: ::core::marker::PhantomData<M> = ::core::marker::PhantomData,
+    }
+);
+
+#[test]
+fn test_impl_cmp() {
+    let a = TestWrapperCmp::const_from(String::from("a"));
+    let b = TestWrapperCmp::const_from(String::from("b"));
+
+    assert!(a == TestWrapperCmp::const_from(String::from("a")));
+    assert!(a != b);
+    assert!(a < b);
+    assert_eq!(a.cmp(&b), Ordering::Less);
+    assert_eq!(a.partial_cmp(&b), Some(Ordering::Less));
+    assert_eq!(hash_one(&a), hash_one(&String::from("a")));
+}
+
+#[test]
+fn test_impl_cmp_complex() {
+    // `NotComparable` implements none of the traits, only the inner field
+    // matters.
+    let a = TestComplexWrapperCmp::<'_, NotComparable>::const_from(1);
+    let b = TestComplexWrapperCmp::<'_, NotComparable>::const_from(2);
+
+    assert!(a != b);
+    assert!(a < b);
+    assert_eq!(b.cmp(&a), Ordering::Greater);
+    assert_eq!(hash_one(&a), hash_one(&1u32));
+
+    _assert_impl_eq::<TestComplexWrapperCmp<'_, NotComparable>>();
+    _assert_impl_ord::<TestComplexWrapperCmp<'_, NotComparable>>();
+    _assert_impl_hash::<TestComplexWrapperCmp<'_, NotComparable>>();
+}
+
+// === PartialEq, Eq, PartialOrd, Ord, Hash ===
+
+// === PartialEq<T>, PartialOrd<T> ===
+
+wrapper!(
+    #[wrapper_impl(PartialEq<String>)]
+    #[wrapper_impl(PartialEq<str>)]
+    #[wrapper_impl(PartialEq<&str>)]
+    #[wrapper_impl(PartialOrd<String>)]
+    pub struct TestWrapperCmpString(String);
+);
+
+wrapper!(
+    #[wrapper_impl(PartialEq<Vec<u8>>)]
+    #[wrapper_impl(PartialEq<[u8]>)]
+    #[wrapper_impl(PartialEq<&[u8]>)]
+    pub struct TestComplexWrapperCmpBytes<'a> {
+        inner_can_be_any_name: Vec<u8>,
+        _a: ::core::marker::PhantomData<&'a ()>,
+    }
+);
+
+wrapper!(
+    #[wrapper_impl(PartialEq)]
+    #[wrapper_impl(PartialEq<u32>)]
+    #[wrapper_impl(PartialOrd)]
+    #[wrapper_impl(PartialOrd<u32>)]
+    pub struct TestWrapperCmpMixed(u32);
+);
+
+wrapper!(
+    #[wrapper_impl(PartialEq<str>)]
+    #[wrapper_impl(PartialEq<&str>)]
+    #[wrapper_impl(PartialEq<u32>)]
+    #[wrapper_impl(PartialOrd<u32>)]
+    pub struct TestWrapperCmpGeneric<T>(T);
+);
+
+wrapper!(
+    #[wrapper_impl(PartialEq<&'a str>)]
+    #[wrapper_impl(PartialOrd<&'a str>)]
+    pub struct TestWrapperCmpGenericRef<'a>(&'a str);
+);
+
+#[test]
+fn test_impl_cmp_target() {
+    let s = TestWrapperCmpString::const_from(String::from("abc"));
+
+    assert!(s == "abc");
+    assert!("abc" == s);
+    assert!(s == *"abc");
+    assert!(*"abc" == s);
+    let (abb, abc, abd) = (
+        String::from("abb"),
+        String::from("abc"),
+        String::from("abd"),
+    );
+
+    assert!(s == abc);
+    assert!(abc == s);
+    assert!(s < abd);
+    assert!(abb < s);
+
+    let b = TestComplexWrapperCmpBytes {
+        inner_can_be_any_name: b"abc".to_vec(),
+        _a: ::core::marker::PhantomData,
+    };
+
+    let slice: &[u8] = b"abc";
+
+    assert!(b == slice);
+    assert!(slice == b);
+    assert!(b == b"abc"[..]);
+    assert!(b == b"abc".to_vec());
+
+    let m = TestWrapperCmpMixed::const_from(1);
+
+    assert!(m == TestWrapperCmpMixed::const_from(1));
+    assert!(m == 1);
+    assert!(2 > m);
+    assert!(m < TestWrapperCmpMixed::const_from(2));
+
+    let g = TestWrapperCmpGeneric::const_from(String::from("abc"));
+
+    assert!(g == *"abc");
+    assert!(*"abc" == g);
+    assert!(g == "abc");
+    assert!("abc" == g);
+
+    let r = TestWrapperCmpGenericRef::const_from("abc");
+
+    assert!(r == "abc");
+    assert!("abd" > r);
+
+    let g = TestWrapperCmpGeneric::const_from(1u32);
+
+    assert!(g == 1);
+    assert!(2 > g);
+}
+
+// === PartialEq<T>, PartialOrd<T> ===
+
+// === Hasher, BuildHasher ===
+
+/// Records the last written integer, to check which method is called.
+#[derive(Default)]
+pub struct RecordingHasher {
+    bytes: usize,
+    last: u128,
+}
+
+impl Hasher for RecordingHasher {
+    fn finish(&self) -> u64 {
+        self.last as u64 + self.bytes as u64
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.bytes += bytes.len();
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.last = i as u128;
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        self.last = i as u128;
+    }
+}
+
+#[derive(Default)]
+pub struct RecordingState;
+
+impl BuildHasher for RecordingState {
+    type Hasher = RecordingHasher;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        RecordingHasher::default()
+    }
+}
+
+wrapper!(
+    #[wrapper_impl(Hasher)]
+    #[wrapper_impl(From)]
+    pub struct TestWrapperHasher(RecordingHasher);
+);
+
+wrapper!(
+    #[wrapper_impl(Hasher)]
+    #[wrapper_impl(From)]
+    pub struct TestComplexWrapperHasher<'a, H> {
+        inner_can_be_any_name: H,
+        _a: ::core::marker::PhantomData<&'a ()> = ::core::marker::PhantomData,
+    }
+);
+
+wrapper!(
+    #[wrapper_impl(BuildHasher)]
+    pub struct TestWrapperBuildHasher(RecordingState);
+);
+
+wrapper!(
+    #[wrapper_impl(BuildHasher<TestWrapperHasher>)]
+    pub struct TestWrapperBuildHasherTarget(RecordingState);
+);
+
+wrapper!(
+    #[wrapper_impl(BuildHasher<TestComplexWrapperHasher<'static, H::Hasher>>)]
+    pub struct TestComplexWrapperBuildHasherTarget<H: BuildHasher> {
+        inner_can_be_any_name: H,
+        extra: u8,
+    }
+);
+
+mod hasher {
+    use wrapper_lite::*;
+
+    use super::RecordingHasher;
+
+    wrapper!(
+        #[wrapper_impl(Hasher)]
+        pub struct TestComplexWrapperHasherNoDefault {
+            inner_can_be_any_name: RecordingHasher,
+            pub(super) extra: u8,
+        }
+    );
+
+    impl From<RecordingHasher> for TestComplexWrapperHasherNoDefault {
+        fn from(inner: RecordingHasher) -> Self {
+            Self {
+                inner_can_be_any_name: inner,
+                extra: 42,
+            }
+        }
+    }
+}
+
+wrapper!(
+    #[wrapper_impl(BuildHasher<hasher::TestComplexWrapperHasherNoDefault>)]
+    pub struct TestWrapperBuildHasherNamedTarget(RecordingState);
+);
+
+#[test]
+fn test_impl_Hasher() {
+    let mut a = TestWrapperHasher::const_from(RecordingHasher::default());
+    a.write_u32(42);
+    a.write(b"abc");
+    assert_eq!(a.finish(), 45);
+    a.write_i128(7);
+    assert_eq!(a.finish(), 10);
+
+    let mut b = TestComplexWrapperHasher::const_from(RecordingHasher::default());
+    1u32.hash(&mut b);
+    assert_eq!(b.finish(), 1);
+
+    let state = TestWrapperBuildHasher::const_from(RecordingState);
+    let _: RecordingHasher = state.build_hasher();
+
+    let state = TestWrapperBuildHasherTarget::const_from(RecordingState);
+    let mut hasher: TestWrapperHasher = state.build_hasher();
+    hasher.write_u32(1);
+    assert_eq!(hasher.finish(), 1);
+
+    let mut map = ::std::collections::HashMap::with_hasher(state);
+    map.insert(1u32, "a");
+    assert_eq!(map[&1], "a");
+
+    let state = TestComplexWrapperBuildHasherTarget {
+        inner_can_be_any_name: RecordingState,
+        extra: 0,
+    };
+    let _: TestComplexWrapperHasher<'_, RecordingHasher> = state.build_hasher();
+
+    let state = TestWrapperBuildHasherNamedTarget::const_from(RecordingState);
+    let mut hasher = state.build_hasher();
+    assert_eq!(hasher.extra, 42);
+    hasher.write_u32(3);
+    assert_eq!(hasher.finish(), 3);
+}
+
+// === Hasher, BuildHasher ===
+
+// === utilities ===
+
+fn hash_one<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn _assert_impl_eq<T>()
+where
+    T: ::core::cmp::Eq,
+{
+}
+
+fn _assert_impl_ord<T>()
+where
+    T: ::core::cmp::Ord,
+{
+}
+
+fn _assert_impl_hash<T>()
+where
+    T: ::core::hash::Hash,
+{
+}
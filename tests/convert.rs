@@ -0,0 +1,265 @@
+#![allow(unused)]
+#![allow(unreachable_pub)]
+#![allow(dead_code)]
+#![allow(non_snake_case)]
+
+use wrapper_lite::*;
+
+// === FromStr ===
+
+wrapper!(
+    #[wrapper_impl(FromStr)]
+    #[derive(Debug, PartialEq)]
+    pub struct TestWrapperFromStr(u16);
+);
+
+wrapper!(
+    #[wrapper_impl(FromStr)]
+    #[derive(Debug, PartialEq)]
+    pub struct TestComplexWrapperFromStr<'a> {
+        inner_can_be_any_name: u16,
+        _a: ::core::marker::PhantomData<&'a ()> = ::core::marker::PhantomData,
+    }
+);
+
+#[test]
+fn test_impl_FromStr() {
+    assert_eq!(
+        "8080".parse::<TestWrapperFromStr>(),
+        Ok(TestWrapperFromStr::const_from(8080))
+    );
+    assert_eq!(
+        "8080".parse::<TestComplexWrapperFromStr<'_>>(),
+        Ok(TestComplexWrapperFromStr::const_from(8080))
+    );
+
+    let err: <u16 as core::str::FromStr>::Err = "65536".parse::<TestWrapperFromStr>().unwrap_err();
+    assert_eq!(err, "65536".parse::<u16>().unwrap_err());
+}
+
+// === FromStr ===
+
+// === Into, IntoInner ===
+
+wrapper!(
+    #[wrapper_impl(Into)]
+    pub struct TestWrapperInto(String);
+);
+
+wrapper!(
+    #[wrapper_impl(Into)]
+    pub struct TestComplexWrapperInto<'a> {
+        inner_can_be_any_name: String,
+        _a: ::core::marker::PhantomData<&'a ()>,
+        _b: Vec<u8>,
+    }
+);
+
+wrapper!(
+    #[wrapper_impl(IntoInner)]
+    pub struct TestWrapperIntoInnerGeneric<'a, P>(&'a P);
+);
+
+wrapper!(
+    #[wrapper_impl(IntoInner)]
+    pub struct TestComplexWrapperIntoInnerGeneric<P> {
+        inner_can_be_any_name: P,
+        _b: Vec<u8>,
+    }
+);
+
+#[test]
+fn test_impl_Into() {
+    let wrapper = TestWrapperInto::const_from(String::from("Hello"));
+    let inner: String = wrapper.into();
+    assert_eq!(inner, "Hello");
+    assert_eq!(
+        TestWrapperInto::const_from(String::from("Hello")).into_inner(),
+        "Hello"
+    );
+
+    let wrapper = TestComplexWrapperInto {
+        inner_can_be_any_name: String::from("Hello"),
+        _a: ::core::marker::PhantomData,
+        _b: vec![1, 2, 3],
+    };
+    assert_eq!(String::from(wrapper), "Hello");
+
+    let data = 42u8;
+    assert_eq!(
+        TestWrapperIntoInnerGeneric::const_from(&data).into_inner(),
+        &42
+    );
+
+    let wrapper = TestComplexWrapperIntoInnerGeneric {
+        inner_can_be_any_name: 42u8,
+        _b: vec![1, 2, 3],
+    };
+    assert_eq!(wrapper.into_inner(), 42);
+}
+
+// === Into, IntoInner ===
+
+// === TryFrom ===
+
+mod validated {
+    use wrapper_lite::*;
+
+    fn is_lowercase(inner: &str) -> bool {
+        inner.chars().all(|c| c.is_ascii_lowercase())
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct PortError;
+
+    fn check_port(inner: &u16) -> Result<(), PortError> {
+        if *inner >= 1024 {
+            Ok(())
+        } else {
+            Err(PortError)
+        }
+    }
+
+    wrapper!(
+        #[wrapper_impl(AsRef)]
+        #[wrapper_impl(TryFrom(validate = is_lowercase))]
+        #[derive(Debug, PartialEq)]
+        pub struct TestWrapperSlug(String);
+    );
+
+    wrapper!(
+        #[wrapper_impl(TryFrom(validate = self::check_port, error = PortError))]
+        #[derive(Debug, PartialEq)]
+        pub struct TestWrapperPort(u16);
+    );
+
+    wrapper!(
+        #[wrapper_impl(TryFrom(validate = check_port, error = PortError,))]
+        #[derive(Debug, PartialEq)]
+        pub struct TestComplexWrapperPort<'a> {
+            inner_can_be_any_name: u16,
+            _a: ::core::marker::PhantomData<&'a ()> = ::core::marker::PhantomData,
+        }
+    );
+}
+
+#[test]
+fn test_impl_TryFrom() {
+    use core::convert::TryFrom;
+
+    use validated::*;
+
+    let slug = TestWrapperSlug::try_new(String::from("abc")).unwrap();
+    assert_eq!(slug.as_inner(), "abc");
+    assert_eq!(
+        TestWrapperSlug::try_from(String::from("aBc")),
+        Err(String::from("aBc"))
+    );
+
+    assert!(TestWrapperPort::try_from(8080).is_ok());
+    assert_eq!(TestWrapperPort::try_new(80), Err(PortError));
+    assert_eq!(
+        unsafe { TestWrapperPort::new_unchecked(8080) },
+        TestWrapperPort::try_new(8080).unwrap()
+    );
+
+    assert!(TestComplexWrapperPort::try_from(8080).is_ok());
+    assert_eq!(TestComplexWrapperPort::try_new(80), Err(PortError));
+}
+
+// === TryFrom ===
+
+// === Default ===
+
+struct NotDefault;
+
+wrapper!(
+    #[wrapper_impl(Default)]
+    #[derive(Debug, PartialEq)]
+    pub struct TestWrapperDefault(u32);
+);
+
+wrapper!(
+    #[wrapper_impl(Default)]
+    pub struct TestComplexWrapperDefault<'a, M> {
+        inner_can_be_any_name: String,
+        _a: ::core::marker::PhantomData<&'a ()> = ::core::marker::PhantomData,
+        _marker: ::core::marker::PhantomData<M> = ::core::marker::PhantomData,
+        extra: u8 = 42,
+    }
+);
+
+#[test]
+fn test_impl_Default() {
+    assert_eq!(
+        TestWrapperDefault::default(),
+        TestWrapperDefault::const_from(0)
+    );
+
+    // `NotDefault` does not implement `Default`, only the inner field matters.
+    let wrapper = TestComplexWrapperDefault::<'_, NotDefault>::default();
+    assert_eq!(wrapper.inner_can_be_any_name, "");
+    assert_eq!(wrapper.extra, 42);
+}
+
+// === Default ===
+
+// === RefCast ===
+
+wrapper!(
+    #[wrapper_impl(RefCast)]
+    #[derive(Debug, PartialEq)]
+    pub struct TestWrapperRefCast(String);
+);
+
+wrapper!(
+    #[wrapper_impl(AsRef)]
+    #[wrapper_impl(RefCast)]
+    pub struct TestWrapperRefCastGeneric<'a, P>(&'a P);
+);
+
+wrapper!(
+    #[wrapper_impl(RefCast)]
+    #[derive(Debug, PartialEq)]
+    pub struct TestComplexWrapperRefCast<M> {
+        inner_can_be_any_name: u32,
+        _marker: ::core::marker::PhantomData<M> = ::core::marker::PhantomData,
+    }
+);
+
+const TEST_REF_CAST_CONST: &TestWrapperRefCastGeneric<'static, u8> =
+    TestWrapperRefCastGeneric::from_ref(&&42);
+
+#[test]
+fn test_impl_RefCast() {
+    let mut inner = String::from("Hello");
+
+    let wrapper = TestWrapperRefCast::from_ref(&inner);
+    assert_eq!(
+        wrapper,
+        &TestWrapperRefCast::const_from("Hello".to_string())
+    );
+    assert!(core::ptr::eq(&wrapper.inner, &inner));
+
+    TestWrapperRefCast::from_mut(&mut inner)
+        .inner
+        .push_str(", world");
+    assert_eq!(inner, "Hello, world");
+
+    assert_eq!(**TEST_REF_CAST_CONST.as_inner(), 42);
+}
+
+#[test]
+fn test_impl_RefCast_complex() {
+    let mut inner = 41;
+
+    *TestComplexWrapperRefCast::<()>::from_mut(&mut inner) =
+        TestComplexWrapperRefCast::const_from(42);
+    assert_eq!(inner, 42);
+
+    let wrapper = TestComplexWrapperRefCast::<()>::from_ref(&inner);
+    assert_eq!(wrapper, &TestComplexWrapperRefCast::const_from(42));
+    assert!(core::ptr::eq(&wrapper.inner_can_be_any_name, &inner));
+}
+
+// === RefCast ===
@@ -0,0 +1,82 @@
+#![cfg(feature = "std")]
+#![allow(unused)]
+#![allow(unreachable_pub)]
+#![allow(dead_code)]
+#![allow(non_snake_case)]
+
+use std::error::Error;
+use std::{fmt, io};
+
+use wrapper_lite::*;
+
+// === Error ===
+
+#[derive(Debug)]
+pub struct TestErrorWithSource(io::Error);
+
+impl fmt::Display for TestErrorWithSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("with source")
+    }
+}
+
+impl Error for TestErrorWithSource {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+wrapper!(
+    #[wrapper_impl(Debug)]
+    #[wrapper_impl(Display)]
+    #[wrapper_impl(Error)]
+    pub struct TestWrapperError(io::Error);
+);
+
+wrapper!(
+    #[wrapper_impl(Display)]
+    #[wrapper_impl(Error)]
+    #[derive(Debug)]
+    pub struct TestComplexWrapperError<'a> {
+        inner_can_be_any_name: TestErrorWithSource,
+        _a: ::core::marker::PhantomData<&'a ()>,
+    }
+);
+
+wrapper!(
+    #[wrapper_impl(Debug)]
+    #[wrapper_impl(Display)]
+    #[wrapper_impl(Error)]
+    pub struct TestWrapperErrorGeneric<E>(E);
+);
+
+#[test]
+fn test_impl_Error() {
+    let error = TestWrapperError::const_from(io::Error::new(io::ErrorKind::Other, "oops"));
+    assert_eq!(error.to_string(), "oops");
+    assert!(error.source().is_none());
+
+    let error: Box<dyn Error> = Box::new(error);
+    assert_eq!(error.to_string(), "oops");
+
+    let error = TestComplexWrapperError {
+        inner_can_be_any_name: TestErrorWithSource(io::Error::new(io::ErrorKind::Other, "inner")),
+        _a: ::core::marker::PhantomData,
+    };
+    assert_eq!(error.to_string(), "with source");
+    assert_eq!(error.source().unwrap().to_string(), "inner");
+
+    let error = TestWrapperErrorGeneric::const_from(fmt::Error);
+    assert!(error.source().is_none());
+    _assert_impl_error::<TestWrapperErrorGeneric<fmt::Error>>();
+}
+
+// === Error ===
+
+// === utilities ===
+
+fn _assert_impl_error<T>()
+where
+    T: Error + Send + Sync + 'static,
+{
+}
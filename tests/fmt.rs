@@ -0,0 +1,116 @@
+#![allow(unused)]
+#![allow(unreachable_pub)]
+#![allow(dead_code)]
+#![allow(non_snake_case)]
+
+use wrapper_lite::*;
+
+// === LowerHex, UpperHex, Binary, Octal, LowerExp, UpperExp, Pointer ===
+
+wrapper!(
+    #[wrapper_impl(LowerHex)]
+    #[wrapper_impl(UpperHex)]
+    #[wrapper_impl(Binary)]
+    #[wrapper_impl(Octal)]
+    #[wrapper_impl(LowerExp)]
+    #[wrapper_impl(UpperExp)]
+    pub struct TestWrapperNumericFmt(u32);
+);
+
+wrapper!(
+    #[wrapper_impl(NumericFmt)]
+    pub struct TestComplexWrapperNumericFmt<'a> {
+        inner_can_be_any_name: u32,
+        _a: ::core::marker::PhantomData<&'a ()>,
+    }
+);
+
+wrapper!(
+    #[wrapper_impl(NumericFmt)]
+    pub struct TestWrapperNumericFmtGeneric<P>(P);
+);
+
+wrapper!(
+    #[wrapper_impl(Pointer)]
+    pub struct TestWrapperPointer<'a>(&'a u8);
+);
+
+#[test]
+fn test_impl_numeric_fmt() {
+    let a = TestWrapperNumericFmt::const_from(255);
+
+    assert_eq!(format!("{:x}", a), "ff");
+    assert_eq!(format!("{:#06x}", a), "0x00ff");
+    assert_eq!(format!("{:X}", a), "FF");
+    assert_eq!(format!("{:>4X}", a), "  FF");
+    assert_eq!(format!("{:b}", a), "11111111");
+    assert_eq!(format!("{:#o}", a), "0o377");
+    assert_eq!(format!("{:e}", a), "2.55e2");
+    assert_eq!(format!("{:E}", a), "2.55E2");
+
+    let b = TestComplexWrapperNumericFmt {
+        inner_can_be_any_name: 10,
+        _a: ::core::marker::PhantomData,
+    };
+
+    assert_eq!(format!("{:x}", b), "a");
+    assert_eq!(format!("{:08b}", b), "00001010");
+    assert_eq!(format!("{:e}", b), "1e1");
+
+    assert_eq!(
+        format!(
+            "{:.1e}",
+            TestWrapperNumericFmtGeneric::const_from(1234.5f64)
+        ),
+        "1.2e3"
+    );
+    assert_eq!(
+        format!("{:x}", TestWrapperNumericFmtGeneric::const_from(-1i8)),
+        "ff"
+    );
+}
+
+#[test]
+fn test_impl_Pointer() {
+    let data = 42u8;
+    let a = TestWrapperPointer::const_from(&data);
+
+    assert_eq!(format!("{:p}", a), format!("{:p}", &data));
+}
+
+// === LowerHex, UpperHex, Binary, Octal, LowerExp, UpperExp, Pointer ===
+
+// === FmtWrite ===
+
+wrapper!(
+    #[wrapper_impl(FmtWrite)]
+    pub struct TestWrapperFmtWrite(String);
+);
+
+wrapper!(
+    #[wrapper_impl(FmtWrite)]
+    pub struct TestComplexWrapperFmtWrite<'a, W> {
+        inner_can_be_any_name: W,
+        _a: ::core::marker::PhantomData<&'a ()>,
+    }
+);
+
+#[test]
+fn test_impl_FmtWrite() {
+    use core::fmt::Write;
+
+    let mut a = TestWrapperFmtWrite::const_from(String::new());
+    a.write_str("a").unwrap();
+    a.write_char('b').unwrap();
+    write!(a, "{:02}", 1).unwrap();
+    assert_eq!(a.inner, "ab01");
+
+    let mut b = TestComplexWrapperFmtWrite {
+        inner_can_be_any_name: String::new(),
+        _a: ::core::marker::PhantomData,
+    };
+    write!(b, "{}-{}", 1, 2).unwrap();
+    assert_eq!(b.inner_can_be_any_name, "1-2");
+}
+
+// === FmtWrite ===
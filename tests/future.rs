@@ -0,0 +1,183 @@
+#![allow(unused)]
+#![allow(unreachable_pub)]
+#![allow(dead_code)]
+#![allow(non_snake_case)]
+
+use core::future::Future;
+use core::marker::PhantomPinned;
+use core::pin::Pin;
+use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+use wrapper_lite::*;
+
+// === Future ===
+
+wrapper!(
+    #[wrapper_impl(Future)]
+    pub struct TestWrapperFuture<F>(F);
+);
+
+wrapper!(
+    #[wrapper_impl(Future)]
+    pub struct TestWrapperFutureReady(core::future::Ready<u8>);
+);
+
+wrapper!(
+    #[wrapper_impl(Future)]
+    pub struct TestComplexWrapperFuture<F> {
+        inner_can_be_any_name: F,
+        polled: usize,
+        _pinned: PhantomPinned,
+    }
+);
+
+/// Returns `Pending` once, then `Ready(value)`.
+pub struct YieldOnce {
+    value: u8,
+    yielded: bool,
+    _pinned: PhantomPinned,
+}
+
+impl Future for YieldOnce {
+    type Output = u8;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: no field is structurally pinned.
+        let this = unsafe { self.get_unchecked_mut() };
+
+        if this.yielded {
+            Poll::Ready(this.value)
+        } else {
+            this.yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+#[test]
+fn test_impl_Future() {
+    assert_eq!(block_on(TestWrapperFuture::const_from(async { 42 })), 42);
+    assert_eq!(
+        block_on(TestWrapperFutureReady::const_from(core::future::ready(1))),
+        1
+    );
+
+    let fut = TestWrapperFuture::const_from(YieldOnce {
+        value: 7,
+        yielded: false,
+        _pinned: PhantomPinned,
+    });
+    assert_eq!(block_on(fut), 7);
+
+    let fut = TestComplexWrapperFuture {
+        inner_can_be_any_name: YieldOnce {
+            value: 8,
+            yielded: false,
+            _pinned: PhantomPinned,
+        },
+        polled: 0,
+        _pinned: PhantomPinned,
+    };
+    assert_eq!(block_on(fut), 8);
+
+    // Only the inner type decides whether the wrapper type is `Unpin`.
+    _assert_impl_unpin::<TestWrapperFutureReady>();
+    _assert_impl_unpin::<TestComplexWrapperFuture<core::future::Ready<u8>>>();
+}
+
+// === Future ===
+
+// === PinProject ===
+
+wrapper!(
+    #[wrapper_impl(PinProject)]
+    pub struct TestWrapperPinProject<S>(S);
+);
+
+wrapper!(
+    #[wrapper_impl(Future)]
+    #[wrapper_impl(PinProject)]
+    pub struct TestWrapperPinProjectFuture<F>(F);
+);
+
+wrapper!(
+    #[wrapper_impl(PinProject)]
+    pub struct TestComplexWrapperPinProject<'a, S> {
+        inner_can_be_any_name: S,
+        _a: ::core::marker::PhantomData<&'a ()> = ::core::marker::PhantomData,
+        _pinned: PhantomPinned = PhantomPinned,
+    }
+);
+
+#[test]
+fn test_impl_PinProject() {
+    let mut a = Box::pin(TestWrapperPinProject::const_from(YieldOnce {
+        value: 1,
+        yielded: false,
+        _pinned: PhantomPinned,
+    }));
+
+    let mut cx = Context::from_waker(noop_waker());
+    assert_eq!(a.as_mut().as_inner_pin_mut().poll(&mut cx), Poll::Pending);
+    assert_eq!(a.as_mut().as_inner_pin_mut().poll(&mut cx), Poll::Ready(1));
+    assert!(a.as_ref().as_inner_pin().yielded);
+
+    let mut b = Box::pin(TestComplexWrapperPinProject::const_from(String::from("a")));
+    b.as_mut().as_inner_pin_mut().get_mut().push('b');
+    assert_eq!(*b.as_ref().as_inner_pin(), "ab");
+
+    // `Future` generates the pin projection methods by itself, and can be
+    // combined with `PinProject`.
+    let mut c = Box::pin(TestWrapperFuture::const_from(core::future::ready(2)));
+    assert_eq!(c.as_mut().as_inner_pin_mut().poll(&mut cx), Poll::Ready(2));
+
+    let mut d = Box::pin(TestWrapperPinProjectFuture::const_from(YieldOnce {
+        value: 3,
+        yielded: false,
+        _pinned: PhantomPinned,
+    }));
+    assert_eq!(d.as_mut().poll(&mut cx), Poll::Pending);
+    assert!(d.as_ref().as_inner_pin().yielded);
+    assert_eq!(block_on(d), 3);
+
+    // Only the inner type decides whether the wrapper type is `Unpin`.
+    _assert_impl_unpin::<TestComplexWrapperPinProject<'_, String>>();
+}
+
+// === PinProject ===
+
+// === utilities ===
+
+fn block_on<F: Future>(fut: F) -> F::Output {
+    let mut cx = Context::from_waker(noop_waker());
+    let mut fut = Box::pin(fut);
+
+    loop {
+        if let Poll::Ready(output) = fut.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+fn noop_waker() -> &'static Waker {
+    fn noop_raw_waker() -> RawWaker {
+        fn clone(_: *const ()) -> RawWaker {
+            noop_raw_waker()
+        }
+
+        fn noop(_: *const ()) {}
+
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+        RawWaker::new(core::ptr::null(), &VTABLE)
+    }
+
+    Box::leak(Box::new(unsafe { Waker::from_raw(noop_raw_waker()) }))
+}
+
+fn _assert_impl_unpin<T>()
+where
+    T: Unpin,
+{
+}
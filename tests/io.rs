@@ -0,0 +1,86 @@
+#![cfg(feature = "std")]
+#![allow(unused)]
+#![allow(unreachable_pub)]
+#![allow(dead_code)]
+#![allow(non_snake_case)]
+
+use std::io::{self, BufRead, Cursor, IoSlice, Read, Seek, SeekFrom, Write};
+
+use wrapper_lite::*;
+
+// === Read, Write, Seek, BufRead ===
+
+wrapper!(
+    #[wrapper_impl(Read)]
+    #[wrapper_impl(Write)]
+    #[wrapper_impl(Seek)]
+    #[wrapper_impl(BufRead)]
+    pub struct TestWrapperIo(Cursor<Vec<u8>>);
+);
+
+wrapper!(
+    #[wrapper_impl(Read)]
+    #[wrapper_impl(BufRead)]
+    pub struct TestWrapperIoGeneric<R>(R);
+);
+
+wrapper!(
+    #[wrapper_impl(Write)]
+    pub struct TestComplexWrapperIo<'a> {
+        inner_can_be_any_name: Vec<u8>,
+        written: usize,
+        _a: ::core::marker::PhantomData<&'a ()>,
+    }
+);
+
+#[test]
+fn test_impl_io() {
+    let mut a = TestWrapperIo::const_from(Cursor::new(Vec::new()));
+
+    a.write_all(b"hello\n").unwrap();
+    assert_eq!(
+        a.write_vectored(&[IoSlice::new(b"wor"), IoSlice::new(b"ld")])
+            .unwrap(),
+        5
+    );
+    write!(a, "!").unwrap();
+    a.flush().unwrap();
+    assert_eq!(a.stream_position().unwrap(), 12);
+
+    a.seek(SeekFrom::Start(0)).unwrap();
+
+    let mut line = String::new();
+    a.read_line(&mut line).unwrap();
+    assert_eq!(line, "hello\n");
+
+    let mut buf = [0u8; 3];
+    a.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"wor");
+
+    let mut rest = String::new();
+    a.read_to_string(&mut rest).unwrap();
+    assert_eq!(rest, "ld!");
+}
+
+#[test]
+fn test_impl_io_complex() {
+    let mut a = TestWrapperIoGeneric::const_from(&b"a,b,c"[..]);
+    let mut buf = Vec::new();
+    a.read_until(b',', &mut buf).unwrap();
+    assert_eq!(buf, b"a,");
+    assert_eq!(a.fill_buf().unwrap(), b"b,c");
+    a.consume(2);
+    buf.clear();
+    a.read_to_end(&mut buf).unwrap();
+    assert_eq!(buf, b"c");
+
+    let mut b = TestComplexWrapperIo {
+        inner_can_be_any_name: Vec::new(),
+        written: 0,
+        _a: ::core::marker::PhantomData,
+    };
+    io::copy(&mut &b"data"[..], &mut b).unwrap();
+    assert_eq!(b.inner_can_be_any_name, b"data");
+}
+
+// === Read, Write, Seek, BufRead ===
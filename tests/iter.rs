@@ -0,0 +1,284 @@
+#![allow(unused)]
+#![allow(unreachable_pub)]
+#![allow(dead_code)]
+#![allow(non_snake_case)]
+
+use std::collections::{BTreeMap, HashSet};
+
+use wrapper_lite::*;
+
+// === IntoIterator, FromIterator, Extend ===
+
+wrapper!(
+    #[wrapper_impl(IntoIterator)]
+    #[wrapper_impl(FromIterator)]
+    #[wrapper_impl(Extend)]
+    #[derive(Debug, PartialEq)]
+    pub struct TestWrapperIter(Vec<u32>);
+);
+
+wrapper!(
+    #[wrapper_impl(IntoIterator)]
+    #[wrapper_impl(FromIterator)]
+    #[wrapper_impl(Extend)]
+    pub struct TestWrapperIterGeneric<T>(HashSet<T>);
+);
+
+wrapper!(
+    #[wrapper_impl(IntoIterator)]
+    #[wrapper_impl(FromIterator)]
+    #[wrapper_impl(Extend)]
+    pub struct TestComplexWrapperIter<'a, K, V> {
+        inner_can_be_any_name: BTreeMap<K, V>,
+        _a: ::core::marker::PhantomData<&'a ()> = ::core::marker::PhantomData,
+        extra: u8 = 42,
+    }
+);
+
+wrapper!(
+    #[wrapper_impl(IntoIterator)]
+    #[wrapper_impl(Extend)]
+    pub struct TestComplexWrapperIterNoDefault<'a> {
+        inner_can_be_any_name: Vec<u32>,
+        _a: ::core::marker::PhantomData<&'a ()>,
+    }
+);
+
+#[test]
+fn test_impl_IntoIterator() {
+    let mut list: TestWrapperIter = (1..=3).collect();
+    assert_eq!(list, TestWrapperIter::const_from(vec![1, 2, 3]));
+
+    list.extend(vec![4, 5]);
+    list.extend(&[6]);
+
+    for x in &mut list {
+        *x *= 2;
+    }
+
+    let mut sum = 0;
+    for x in &list {
+        sum += *x;
+    }
+    assert_eq!(sum, 42);
+    assert_eq!(list.into_iter().collect::<Vec<_>>(), [2, 4, 6, 8, 10, 12]);
+
+    let mut set: TestWrapperIterGeneric<&str> = vec!["a", "b", "a"].into_iter().collect();
+    set.extend(vec!["c"]);
+    assert_eq!((&set).into_iter().count(), 3);
+}
+
+#[test]
+fn test_impl_IntoIterator_complex() {
+    let mut map: TestComplexWrapperIter<'_, &str, u32> =
+        vec![("a", 1), ("b", 2)].into_iter().collect();
+    assert_eq!(map.extra, 42);
+
+    map.extend(vec![("c", 3)]);
+
+    for (_, v) in &mut map {
+        *v += 10;
+    }
+
+    assert_eq!(
+        map.into_iter().collect::<Vec<_>>(),
+        [("a", 11), ("b", 12), ("c", 13)]
+    );
+
+    let mut list = TestComplexWrapperIterNoDefault {
+        inner_can_be_any_name: vec![1],
+        _a: ::core::marker::PhantomData,
+    };
+    list.extend(vec![2, 3]);
+    assert_eq!((&list).into_iter().copied().sum::<u32>(), 6);
+}
+
+// === IntoIterator, FromIterator, Extend ===
+
+// === Sum, Product ===
+
+wrapper!(
+    #[wrapper_impl(Sum)]
+    #[wrapper_impl(Product)]
+    #[derive(Debug, PartialEq)]
+    pub struct TestWrapperSum(u64);
+);
+
+wrapper!(
+    #[wrapper_impl(Sum)]
+    #[wrapper_impl(Product)]
+    #[derive(Debug, PartialEq)]
+    pub struct TestWrapperSumGeneric<P>(P);
+);
+
+wrapper!(
+    #[wrapper_impl(Sum)]
+    #[wrapper_impl(Product)]
+    #[derive(Debug, PartialEq)]
+    pub struct TestComplexWrapperSum<'a> {
+        inner_can_be_any_name: f64,
+        _a: ::core::marker::PhantomData<&'a ()> = ::core::marker::PhantomData,
+    }
+);
+
+#[test]
+fn test_impl_Sum() {
+    let list: Vec<TestWrapperSum> = (1..=4).map(TestWrapperSum::const_from).collect();
+
+    assert_eq!(
+        list.iter().sum::<TestWrapperSum>(),
+        TestWrapperSum::const_from(10)
+    );
+    assert_eq!(
+        list.iter().product::<TestWrapperSum>(),
+        TestWrapperSum::const_from(24)
+    );
+    assert_eq!(
+        list.into_iter().sum::<TestWrapperSum>(),
+        TestWrapperSum::const_from(10)
+    );
+    assert_eq!(
+        Vec::<TestWrapperSum>::new()
+            .into_iter()
+            .product::<TestWrapperSum>(),
+        TestWrapperSum::const_from(1)
+    );
+
+    let list = [
+        TestWrapperSumGeneric::const_from(2i8),
+        TestWrapperSumGeneric::const_from(-3i8),
+    ];
+    assert_eq!(
+        list.iter().product::<TestWrapperSumGeneric<i8>>(),
+        TestWrapperSumGeneric::const_from(-6)
+    );
+
+    let list = vec![
+        TestComplexWrapperSum::const_from(0.5),
+        TestComplexWrapperSum::const_from(4.0),
+    ];
+    assert_eq!(
+        list.iter().sum::<TestComplexWrapperSum<'_>>(),
+        TestComplexWrapperSum::const_from(4.5)
+    );
+    assert_eq!(
+        list.into_iter().product::<TestComplexWrapperSum<'_>>(),
+        TestComplexWrapperSum::const_from(2.0)
+    );
+}
+
+// === Sum, Product ===
+
+// === Iterator, DoubleEndedIterator, ExactSizeIterator, FusedIterator ===
+
+wrapper!(
+    #[wrapper_impl(Iterator)]
+    #[wrapper_impl(DoubleEndedIterator)]
+    #[wrapper_impl(ExactSizeIterator)]
+    #[wrapper_impl(FusedIterator)]
+    pub struct TestWrapperIterator(::std::vec::IntoIter<u32>);
+);
+
+wrapper!(
+    #[wrapper_impl(Iterator)]
+    #[wrapper_impl(DoubleEndedIterator)]
+    pub struct TestWrapperIteratorGeneric<I>(I);
+);
+
+wrapper!(
+    #[wrapper_impl(Iterator)]
+    #[wrapper_impl(ExactSizeIterator)]
+    pub struct TestComplexWrapperIterator<'a> {
+        inner_can_be_any_name: ::core::slice::Iter<'a, u32>,
+        step: usize,
+    }
+);
+
+#[test]
+fn test_impl_Iterator() {
+    let new = || TestWrapperIterator::const_from(vec![1, 2, 3, 4, 5].into_iter());
+
+    let mut a = new();
+    assert_eq!(a.len(), 5);
+    assert_eq!(a.size_hint(), (5, Some(5)));
+    assert_eq!(a.next(), Some(1));
+    assert_eq!(a.next_back(), Some(5));
+    assert_eq!(a.nth(1), Some(3));
+    assert_eq!(a.nth_back(0), Some(4));
+    assert_eq!(a.next(), None);
+    assert_eq!(a.next(), None);
+
+    assert_eq!(new().count(), 5);
+    assert_eq!(new().last(), Some(5));
+    assert_eq!(new().fold(0, |acc, x| acc * 10 + x), 12345);
+    assert_eq!(new().rfold(0, |acc, x| acc * 10 + x), 54321);
+    assert!(new().all(|x| x > 0));
+    assert!(new().any(|x| x == 3));
+    assert_eq!(new().find(|x| *x > 2), Some(3));
+    assert_eq!(new().rfind(|x| *x < 3), Some(2));
+    assert_eq!(
+        new().find_map(|x| if x > 3 { Some(x * 2) } else { None }),
+        Some(8)
+    );
+    assert_eq!(new().position(|x| x == 4), Some(3));
+    assert_eq!(new().max(), Some(5));
+    assert_eq!(new().min(), Some(1));
+    assert_eq!(new().rev().collect::<Vec<_>>(), [5, 4, 3, 2, 1]);
+
+    let mut sum = 0;
+    new().for_each(|x| sum += x);
+    assert_eq!(sum, 15);
+
+    assert_eq!(new().collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+    assert_eq!(
+        new().partition::<Vec<_>, _>(|x| x % 2 == 0),
+        (vec![2, 4], vec![1, 3, 5])
+    );
+    assert_eq!(new().reduce(|acc, x| acc * x), Some(120));
+    assert_eq!(new().max_by_key(|x| *x % 3), Some(5));
+    assert_eq!(new().max_by(|x, y| y.cmp(x)), Some(1));
+    assert_eq!(new().min_by_key(|x| *x % 3), Some(3));
+    assert_eq!(new().min_by(|x, y| y.cmp(x)), Some(5));
+    assert_eq!(new().sum::<u32>(), 15);
+    assert_eq!(new().product::<u32>(), 120);
+    assert_eq!(new().cmp(vec![1, 2, 4]), ::core::cmp::Ordering::Less);
+    assert_eq!(
+        new().partial_cmp(vec![1, 2, 3, 4, 5]),
+        Some(::core::cmp::Ordering::Equal)
+    );
+    assert!(new().eq(1..=5));
+    assert!(new().ne(1..5));
+    assert!(new().lt(2..3));
+    assert!(new().le(1..=5));
+    assert!(new().gt(1..5));
+    assert!(new().ge(1..=5));
+
+    _assert_impl_fused::<TestWrapperIterator>();
+
+    let b = TestWrapperIteratorGeneric::const_from((1..4).map(|x| x * 2));
+    assert_eq!(b.rev().collect::<Vec<_>>(), [6, 4, 2]);
+}
+
+#[test]
+fn test_impl_Iterator_complex() {
+    let data = [1, 2, 3];
+    let mut a = TestComplexWrapperIterator {
+        inner_can_be_any_name: data.iter(),
+        step: 1,
+    };
+
+    assert_eq!(a.len(), 3);
+    assert_eq!(a.next(), Some(&1));
+    assert_eq!(a.len(), 2);
+    assert_eq!(a.copied().sum::<u32>(), 5);
+}
+
+// === Iterator, DoubleEndedIterator, ExactSizeIterator, FusedIterator ===
+
+// === utilities ===
+
+fn _assert_impl_fused<T>()
+where
+    T: ::core::iter::FusedIterator,
+{
+}
@@ -0,0 +1,293 @@
+#![allow(unused)]
+#![allow(unreachable_pub)]
+#![allow(dead_code)]
+#![allow(non_snake_case)]
+#![allow(clippy::op_ref)]
+
+use wrapper_lite::*;
+
+// === Arithmetic ===
+
+wrapper!(
+    #[wrapper_impl(Add)]
+    #[wrapper_impl(AddAssign)]
+    #[wrapper_impl(Sub)]
+    #[wrapper_impl(SubAssign)]
+    #[wrapper_impl(Mul)]
+    #[wrapper_impl(MulAssign)]
+    #[wrapper_impl(Div)]
+    #[wrapper_impl(DivAssign)]
+    #[wrapper_impl(Rem)]
+    #[wrapper_impl(RemAssign)]
+    #[wrapper_impl(Neg)]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct TestWrapperArith(i64);
+);
+
+wrapper!(
+    #[wrapper_impl(Add)]
+    #[wrapper_impl(AddAssign)]
+    #[wrapper_impl(Sub)]
+    #[wrapper_impl(SubAssign)]
+    #[wrapper_impl(Mul)]
+    #[wrapper_impl(MulAssign)]
+    #[wrapper_impl(Div)]
+    #[wrapper_impl(DivAssign)]
+    #[wrapper_impl(Rem)]
+    #[wrapper_impl(RemAssign)]
+    #[wrapper_impl(Neg)]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct TestComplexWrapperArith<'a> {
+        inner_can_be_any_name: i64,
+        _a: ::core::marker::PhantomData<&'a ()> = ::core::marker::PhantomData,
+    }
+);
+
+wrapper!(
+    #[wrapper_impl(AddAssign)]
+    pub struct TestComplexWrapperAssignOnly<'a> {
+        inner_can_be_any_name: u32,
+        _a: ::core::marker::PhantomData<&'a ()>,
+    }
+);
+
+#[test]
+fn test_ops_arith() {
+    let a = TestWrapperArith::const_from(7);
+    let b = TestWrapperArith::const_from(2);
+
+    assert_eq!(a + b, TestWrapperArith::const_from(9));
+    assert_eq!(a + &b, TestWrapperArith::const_from(9));
+    assert_eq!(&a + b, TestWrapperArith::const_from(9));
+    assert_eq!(&a + &b, TestWrapperArith::const_from(9));
+    assert_eq!(a + 1, TestWrapperArith::const_from(8));
+    assert_eq!(&a + 1, TestWrapperArith::const_from(8));
+    assert_eq!(a - b, TestWrapperArith::const_from(5));
+    assert_eq!(a * b, TestWrapperArith::const_from(14));
+    assert_eq!(a / b, TestWrapperArith::const_from(3));
+    assert_eq!(a % b, TestWrapperArith::const_from(1));
+    assert_eq!(-a, TestWrapperArith::const_from(-7));
+    assert_eq!(-&a, TestWrapperArith::const_from(-7));
+
+    let mut c = a;
+    c += b;
+    c -= &b;
+    c *= 3;
+    c /= b;
+    c %= TestWrapperArith::const_from(4);
+    assert_eq!(c, TestWrapperArith::const_from(2));
+}
+
+#[test]
+fn test_ops_arith_complex() {
+    let a = TestComplexWrapperArith::const_from(7);
+    let b = TestComplexWrapperArith::const_from(2);
+
+    assert_eq!(a + b, TestComplexWrapperArith::const_from(9));
+    assert_eq!(&a - &b, TestComplexWrapperArith::const_from(5));
+    assert_eq!(a * 2, TestComplexWrapperArith::const_from(14));
+    assert_eq!(-a, TestComplexWrapperArith::const_from(-7));
+
+    let mut c = a;
+    c += b;
+    c %= 5;
+    assert_eq!(c, TestComplexWrapperArith::const_from(4));
+
+    let mut d = TestComplexWrapperAssignOnly {
+        inner_can_be_any_name: 1,
+        _a: ::core::marker::PhantomData,
+    };
+    d += 2;
+    assert_eq!(d.inner_can_be_any_name, 3);
+}
+
+wrapper!(
+    #[wrapper_impl(Add)]
+    #[wrapper_impl(AddAssign)]
+    #[wrapper_impl(Neg)]
+    pub struct TestWrapperArithGeneric<P>(P);
+);
+
+wrapper!(
+    #[wrapper_impl(Add)]
+    #[wrapper_impl(SubAssign)]
+    pub struct TestWrapperArithGenericRef<'a, P>(&'a P);
+);
+
+#[test]
+fn assert_impls_TestWrapperArithGeneric() {
+    _assert_impl_add::<TestWrapperArithGeneric<u8>, TestWrapperArithGeneric<u8>>();
+    _assert_impl_add::<TestWrapperArithGeneric<u8>, u8>();
+    _assert_impl_add::<&TestWrapperArithGeneric<u8>, &TestWrapperArithGeneric<u8>>();
+}
+
+// === Arithmetic ===
+
+// === Bitwise ===
+
+wrapper!(
+    #[wrapper_impl(BitAnd)]
+    #[wrapper_impl(BitAndAssign)]
+    #[wrapper_impl(BitOr)]
+    #[wrapper_impl(BitOrAssign)]
+    #[wrapper_impl(BitXor)]
+    #[wrapper_impl(BitXorAssign)]
+    #[wrapper_impl(Not)]
+    #[wrapper_impl(Shl)]
+    #[wrapper_impl(ShlAssign)]
+    #[wrapper_impl(Shr)]
+    #[wrapper_impl(ShrAssign)]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct TestWrapperBits(u8);
+);
+
+wrapper!(
+    #[wrapper_impl(BitAnd)]
+    #[wrapper_impl(BitOrAssign)]
+    #[wrapper_impl(Not)]
+    #[wrapper_impl(Shl)]
+    #[wrapper_impl(ShrAssign)]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct TestComplexWrapperBits<'a> {
+        inner_can_be_any_name: u8,
+        _a: ::core::marker::PhantomData<&'a ()> = ::core::marker::PhantomData,
+    }
+);
+
+#[test]
+fn test_ops_bits() {
+    let a = TestWrapperBits::const_from(0b1100);
+    let b = TestWrapperBits::const_from(0b1010);
+
+    assert_eq!(a & b, TestWrapperBits::const_from(0b1000));
+    assert_eq!(&a | &b, TestWrapperBits::const_from(0b1110));
+    assert_eq!(a ^ 0b0110, TestWrapperBits::const_from(0b1010));
+    assert_eq!(!a, TestWrapperBits::const_from(0b1111_0011));
+    assert_eq!(a << 2u32, TestWrapperBits::const_from(0b11_0000));
+    assert_eq!(&a >> 2usize, TestWrapperBits::const_from(0b11));
+
+    let mut c = a;
+    c &= b;
+    c |= 0b1;
+    c ^= &b;
+    c <<= 1;
+    c >>= 2i32;
+    assert_eq!(c, TestWrapperBits::const_from(0b01));
+}
+
+#[test]
+fn test_ops_bits_complex() {
+    let a = TestComplexWrapperBits::const_from(0b1100);
+
+    assert_eq!(a & 0b0100, TestComplexWrapperBits::const_from(0b0100));
+    assert_eq!(!a, TestComplexWrapperBits::const_from(0b1111_0011));
+    assert_eq!(a << 1, TestComplexWrapperBits::const_from(0b1_1000));
+
+    let mut c = a;
+    c |= 0b11;
+    c >>= 1;
+    assert_eq!(c, TestComplexWrapperBits::const_from(0b111));
+}
+
+// === Bitwise ===
+
+// === Index, IndexMut ===
+
+wrapper!(
+    #[wrapper_impl(IndexMut)]
+    pub struct TestWrapperIndex(Vec<u32>);
+);
+
+wrapper!(
+    #[wrapper_impl(Index)]
+    pub struct TestWrapperIndexGeneric<T>(Vec<T>);
+);
+
+wrapper!(
+    #[wrapper_impl(IndexMut<usize>)]
+    pub struct TestWrapperIndexTarget(Vec<u32>);
+);
+
+wrapper!(
+    #[wrapper_impl(IndexMut<usize>)]
+    pub struct TestWrapperIndexTargetGeneric<T>(T);
+);
+
+wrapper!(
+    #[wrapper_impl(Index<&str>)]
+    pub struct TestWrapperIndexTargetRef<V>(::std::collections::HashMap<String, V>);
+);
+
+wrapper!(
+    #[wrapper_impl(Index<&'static str>)]
+    pub struct TestComplexWrapperIndexTarget<'a> {
+        inner_can_be_any_name: ::std::collections::BTreeMap<&'static str, u32>,
+        _a: ::core::marker::PhantomData<&'a ()>,
+    }
+);
+
+wrapper!(
+    #[wrapper_impl(IndexMut)]
+    pub struct TestComplexWrapperIndex<'a> {
+        inner_can_be_any_name: Vec<u32>,
+        _a: ::core::marker::PhantomData<&'a ()>,
+    }
+);
+
+#[test]
+fn test_ops_index() {
+    let mut a = TestWrapperIndex::const_from(vec![1, 2, 3]);
+
+    a[0] = 10;
+    a[1..].copy_from_slice(&[20, 30]);
+    assert_eq!(a[0], 10);
+    assert_eq!(&a[..2], [10, 20]);
+    assert_eq!(&a[..], [10, 20, 30]);
+
+    let b = TestWrapperIndexGeneric::const_from(vec![1u8, 2, 3]);
+    assert_eq!(b[2], 3);
+    assert_eq!(&b[1..], [2, 3]);
+
+    let mut c = TestWrapperIndexTarget::const_from(vec![1, 2]);
+    c[1] += 1;
+    assert_eq!(c[1], 3);
+
+    let mut d = TestWrapperIndexTargetGeneric::const_from([1, 2]);
+    d[0] = 3;
+    assert_eq!(d[0], 3);
+
+    let mut map = ::std::collections::HashMap::new();
+    map.insert("a".to_string(), 1);
+
+    let e = TestWrapperIndexTargetRef::const_from(map);
+    assert_eq!(e["a"], 1);
+}
+
+#[test]
+fn test_ops_index_complex() {
+    let mut map = ::std::collections::BTreeMap::new();
+    map.insert("a", 1);
+
+    let a = TestComplexWrapperIndexTarget {
+        inner_can_be_any_name: map,
+        _a: ::core::marker::PhantomData,
+    };
+    assert_eq!(a["a"], 1);
+
+    let mut b = TestComplexWrapperIndex {
+        inner_can_be_any_name: vec![1, 2, 3],
+        _a: ::core::marker::PhantomData,
+    };
+    b[2] = 42;
+    assert_eq!(&b[1..], [2, 42]);
+}
+
+// === Index, IndexMut ===
+
+// === utilities ===
+
+fn _assert_impl_add<T, U>()
+where
+    T: ::core::ops::Add<U>,
+{
+}
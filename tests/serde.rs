@@ -0,0 +1,151 @@
+#![cfg(feature = "serde")]
+#![allow(unused)]
+#![allow(unreachable_pub)]
+#![allow(dead_code)]
+#![allow(non_snake_case)]
+
+use std::collections::BTreeMap;
+
+use wrapper_lite::*;
+
+// === Serialize, Deserialize ===
+
+wrapper!(
+    #[wrapper_impl(Serialize)]
+    #[wrapper_impl(Deserialize)]
+    #[derive(Debug, PartialEq)]
+    pub struct TestWrapperSerde(String);
+);
+
+wrapper!(
+    #[wrapper_impl(Serialize)]
+    #[wrapper_impl(Deserialize)]
+    #[derive(Debug, PartialEq)]
+    pub struct TestWrapperSerdeGeneric<T>(Vec<T>);
+);
+
+wrapper!(
+    #[wrapper_impl(Serialize)]
+    #[wrapper_impl(Deserialize)]
+    #[derive(Debug, PartialEq)]
+    pub struct TestComplexWrapperSerde<'a> {
+        inner_can_be_any_name: BTreeMap<String, u32>,
+        _a: ::core::marker::PhantomData<&'a ()> = ::core::marker::PhantomData,
+        extra: u8 = 42,
+    }
+);
+
+wrapper!(
+    #[wrapper_impl(Serialize)]
+    pub struct TestComplexWrapperSerializeOnly<'a> {
+        inner_can_be_any_name: &'a str,
+        _marker: ::core::marker::PhantomData<()>,
+    }
+);
+
+wrapper!(
+    #[wrapper_impl(Deserialize)]
+    #[derive(Debug, PartialEq)]
+    pub struct TestWrapperDeserializeBorrowed<'a>(&'a str);
+);
+
+#[test]
+fn test_impl_serde() {
+    let a = TestWrapperSerde::const_from(String::from("hello"));
+    assert_eq!(serde_json::to_string(&a).unwrap(), r#""hello""#);
+    assert_eq!(
+        serde_json::from_str::<TestWrapperSerde>(r#""hello""#).unwrap(),
+        a
+    );
+    assert!(serde_json::from_str::<TestWrapperSerde>("42").is_err());
+
+    let b = TestWrapperSerdeGeneric::const_from(vec![1, 2]);
+    assert_eq!(serde_json::to_string(&b).unwrap(), "[1,2]");
+    assert_eq!(
+        serde_json::from_str::<TestWrapperSerdeGeneric<i32>>("[1,2]").unwrap(),
+        b
+    );
+
+    let c = TestWrapperDeserializeBorrowed::const_from("borrowed");
+    assert_eq!(
+        serde_json::from_str::<TestWrapperDeserializeBorrowed<'_>>(r#""borrowed""#).unwrap(),
+        c
+    );
+}
+
+#[test]
+fn test_impl_serde_complex() {
+    let json = r#"{"a":1}"#;
+
+    let a = serde_json::from_str::<TestComplexWrapperSerde<'_>>(json).unwrap();
+    assert_eq!(a.inner_can_be_any_name["a"], 1);
+    assert_eq!(a.extra, 42);
+    assert_eq!(serde_json::to_string(&a).unwrap(), json);
+
+    let b = TestComplexWrapperSerializeOnly {
+        inner_can_be_any_name: "only",
+        _marker: ::core::marker::PhantomData,
+    };
+    assert_eq!(serde_json::to_string(&b).unwrap(), r#""only""#);
+}
+
+// === Serialize, Deserialize ===
+
+// === SerdeAsString ===
+
+wrapper!(
+    #[wrapper_impl(Display)]
+    #[wrapper_impl(FromStr)]
+    #[wrapper_impl(SerdeAsString)]
+    #[derive(Debug, PartialEq)]
+    pub struct TestWrapperSerdeAsString(u64);
+);
+
+wrapper!(
+    #[wrapper_impl(Display)]
+    #[wrapper_impl(FromStr)]
+    #[wrapper_impl(SerdeAsString)]
+    #[derive(Debug, PartialEq)]
+    pub struct TestWrapperSerdeAsStringGeneric<T>(T);
+);
+
+wrapper!(
+    #[wrapper_impl(Display)]
+    #[wrapper_impl(FromStr)]
+    #[wrapper_impl(SerdeAsString)]
+    #[derive(Debug, PartialEq)]
+    pub struct TestComplexWrapperSerdeAsString<'a> {
+        inner_can_be_any_name: ::std::net::Ipv4Addr,
+        _a: ::core::marker::PhantomData<&'a ()> = ::core::marker::PhantomData,
+    }
+);
+
+#[test]
+fn test_impl_SerdeAsString() {
+    let a = TestWrapperSerdeAsString::const_from(42);
+    assert_eq!(serde_json::to_string(&a).unwrap(), r#""42""#);
+    assert_eq!(
+        serde_json::from_str::<TestWrapperSerdeAsString>(r#""42""#).unwrap(),
+        a
+    );
+
+    let err = serde_json::from_str::<TestWrapperSerdeAsString>(r#""x""#).unwrap_err();
+    assert!(err.to_string().contains("invalid digit"));
+    assert!(serde_json::from_str::<TestWrapperSerdeAsString>("42").is_err());
+
+    let b = TestWrapperSerdeAsStringGeneric::const_from(-1i8);
+    assert_eq!(serde_json::to_string(&b).unwrap(), r#""-1""#);
+    assert_eq!(
+        serde_json::from_str::<TestWrapperSerdeAsStringGeneric<i8>>(r#""-1""#).unwrap(),
+        b
+    );
+
+    let c = TestComplexWrapperSerdeAsString::const_from(::std::net::Ipv4Addr::LOCALHOST);
+    assert_eq!(serde_json::to_string(&c).unwrap(), r#""127.0.0.1""#);
+    assert_eq!(
+        serde_json::from_str::<TestComplexWrapperSerdeAsString<'_>>(r#""127.0.0.1""#).unwrap(),
+        c
+    );
+}
+
+// === SerdeAsString ===
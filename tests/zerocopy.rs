@@ -0,0 +1,103 @@
+#![cfg(feature = "zerocopy")]
+#![allow(unused)]
+#![allow(unreachable_pub)]
+#![allow(dead_code)]
+#![allow(non_snake_case)]
+
+use wrapper_lite::*;
+use zerocopy::byteorder::{BigEndian, U32};
+use zerocopy::{FromBytes, FromZeros, Immutable, IntoBytes, KnownLayout, Unaligned};
+
+// === Zerocopy, Unaligned ===
+
+wrapper!(
+    #[wrapper_impl(AsRef)]
+    #[wrapper_impl(Zerocopy)]
+    #[wrapper_impl(Unaligned)]
+    #[wrapper_impl(Deref)]
+    #[derive(Debug, PartialEq)]
+    pub struct TestWrapperZerocopyBe(U32<BigEndian>);
+);
+
+wrapper!(
+    #[wrapper_impl(Zerocopy)]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    /// Native-endian, so not `Unaligned`.
+    pub struct TestWrapperZerocopyNative(u32);
+);
+
+wrapper!(
+    #[wrapper_impl(Unaligned)]
+    #[wrapper_impl(Zerocopy)]
+    pub struct TestWrapperZerocopyGeneric<T>(T);
+);
+
+wrapper!(
+    #[wrapper_impl(Zerocopy)]
+    #[wrapper_impl(Unaligned)]
+    #[derive(Debug, PartialEq)]
+    pub struct TestComplexWrapperZerocopy<M> {
+        inner_can_be_any_name: [u8; 2],
+        _marker: ::core::marker::PhantomData<M> = ::core::marker::PhantomData,
+    }
+);
+
+#[test]
+fn test_impl_zerocopy() {
+    let bytes = [0x00, 0x00, 0x01, 0xbb, 0xff];
+
+    let (value, rest) = TestWrapperZerocopyBe::ref_from_prefix(&bytes).unwrap();
+    assert_eq!(value.get(), 443);
+    assert_eq!(value.as_inner(), &U32::new(443));
+    assert_eq!(value.as_bytes(), &bytes[..4]);
+    assert_eq!(rest, [0xff]);
+
+    let value = TestWrapperZerocopyNative::read_from_bytes(&443u32.to_ne_bytes()).unwrap();
+    assert_eq!(value, TestWrapperZerocopyNative::const_from(443));
+    assert_eq!(
+        TestWrapperZerocopyNative::new_zeroed(),
+        TestWrapperZerocopyNative::const_from(0)
+    );
+
+    let mut buf = [0u8; 4];
+    TestWrapperZerocopyNative::const_from(1)
+        .write_to(&mut buf)
+        .unwrap();
+    assert_eq!(buf, 1u32.to_ne_bytes());
+
+    let header = TestWrapperZerocopyGeneric::<[u8; 2]>::ref_from_bytes(&bytes[3..]).unwrap();
+    assert_eq!(header.as_bytes(), [0xbb, 0xff]);
+
+    _assert_impl_zerocopy::<TestWrapperZerocopyNative>();
+    _assert_impl_zerocopy::<TestWrapperZerocopyGeneric<[U32<BigEndian>; 2]>>();
+    _assert_impl_unaligned::<TestWrapperZerocopyBe>();
+    _assert_impl_unaligned::<TestWrapperZerocopyGeneric<u8>>();
+}
+
+#[test]
+fn test_impl_zerocopy_complex() {
+    let bytes = [0x01, 0xbb];
+
+    let value = TestComplexWrapperZerocopy::<()>::ref_from_bytes(&bytes).unwrap();
+    assert_eq!(value, &TestComplexWrapperZerocopy::const_from([0x01, 0xbb]));
+    assert_eq!(value.as_bytes(), bytes);
+
+    _assert_impl_zerocopy::<TestComplexWrapperZerocopy<u64>>();
+    _assert_impl_unaligned::<TestComplexWrapperZerocopy<u64>>();
+}
+
+// === Zerocopy, Unaligned ===
+
+// === utilities ===
+
+fn _assert_impl_zerocopy<T>()
+where
+    T: FromBytes + IntoBytes + KnownLayout + Immutable,
+{
+}
+
+fn _assert_impl_unaligned<T>()
+where
+    T: Unaligned,
+{
+}